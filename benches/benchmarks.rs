@@ -20,6 +20,7 @@ fn bench_set_from_unsigned(c: &mut Criterion) {
     });
 }
 
+#[allow(clippy::needless_borrow)]
 fn bench_set_from_string(c: &mut Criterion) {
     c.bench_function("Set b64 from String", move |b| {
         let mut x: Base64 = Base64::new_encode_unsigned(&0, STANDARD);
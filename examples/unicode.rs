@@ -5,19 +5,12 @@ extern crate lb64; // Include base64 crate
 use lb64::{config::Config, Base64};
 
 fn main() {
-    let character_set = &[
-        '😀', '😃', '😄', '😁', '😆', '😅', '😂', '☺', '😊', '😇', '🙃',
-        '😉', '😌', '😍', '😘', '😗', '😙', '😚', '😋', '😛', '😝', '😡',
-        '😠', '😭', '😢', '😩', '😫', '😖', '😣', '☹', '😈', '😮', '😲',
-        '😴', '😺', '😸', '😻', '😹', '😼', '😽', '🙀', '😿', '😾', '😱',
-        '😨', '😰', '😎', '🌕', '🌖', '🌗', '🌘', '🌑', '🌒', '🌓', '🌔',
-        '☃', '☔', '☂', '🐭', '❤', '▶', '☘', '☀', '⚡',
-    ];
-    let conf;
-    match Config::new(character_set, Some('\u{22D4}'), Some(6)) {
-        Ok(config) => conf = config,
+    let alphabet = "😀😃😄😁😆😅😂☺😊😇🙃😉😌😍😘😗😙😚😋😛😝😡😠😭😢😩😫😖😣☹😈😮😲😴😺😸😻😹😼😽🙀😿😾😱😨😰😎🌕🌖🌗🌘🌑🌒🌓🌔☃☔☂🐭❤▶☘☀⚡";
+    let owned = match Config::from_alphabet_str(alphabet, Some('\u{22D4}'), Some(6)) {
+        Ok(config) => config,
         Err(e) => panic!("{}", e), // Panics unecessarily can catch and handle this error
     }; // Create config, panicing on errors
+    let conf = owned.as_config();
 
     let s: &str = "Unicode Base64";
     let b64 = Base64::new_encode_bytes(s.as_bytes(), &conf);
@@ -0,0 +1,673 @@
+use core::ops::{Add, Range};
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{decimal_to_base64_char, error::Base64Error, Base64};
+
+impl<'a> Base64<'a> {
+    /// Adds two Base64 numbers that have the same config
+    ///
+    /// # Parameters:
+    /// other, the Base64 number to add
+    ///
+    /// # Return:
+    /// the sum in the config of self, or None if the configs differ or the sum overflows a u128
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let ten = Base64::new_encode_unsigned(&10, URL_SAFE_NO_PADDING); // Sets ten to K
+    ///     let one = Base64::new_encode_unsigned(&1, URL_SAFE_NO_PADDING); // Sets one to B
+    ///     match ten.checked_add(&one) {
+    ///         Some(value) => println!("{}", value), // Prints L
+    ///         None => println!("Overflow or different configs"),
+    ///     }
+    /// }
+    /// ```
+    pub fn checked_add(&self, other: &Base64) -> Option<Base64<'a>> {
+        if self.conf != other.conf {
+            return None;
+        }
+        let lhs = self.decode_to_unsigned().ok()?;
+        let rhs = other.decode_to_unsigned().ok()?;
+        Some(Base64::new_encode_unsigned(
+            &lhs.checked_add(rhs)?,
+            self.conf,
+        ))
+    }
+
+    /// Multiplies two Base64 numbers that have the same config digit by digit so it isn't limited
+    /// to a u128. Padding and line endings are ignored
+    ///
+    /// # Parameters:
+    /// other, the Base64 number to multiply by
+    ///
+    /// # Return:
+    /// the product in the config of self, or None if the configs differ or the product can't be
+    /// allocated
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_unsigned(&64, URL_SAFE_NO_PADDING); // Sets b64 to BA
+    ///     match b64.checked_mul(&b64) {
+    ///         Some(value) => println!("{}", value), // Prints BAA which is 4096
+    ///         None => println!("Different configs"),
+    ///     }
+    /// }
+    /// ```
+    pub fn checked_mul(&self, other: &Base64) -> Option<Base64<'a>> {
+        if self.conf != other.conf {
+            return None;
+        }
+        let lhs: Vec<u128> = self.digits().collect();
+        let rhs: Vec<u128> = other.digits().collect();
+        Some(self.number_with_digits(&multiply_digits(&lhs, &rhs)?))
+    }
+
+    /// Raises the Base64 number to the power of exp digit by digit so it isn't limited to a u128.
+    /// Padding and line endings are ignored
+    ///
+    /// # Parameters:
+    /// exp, the exponent
+    ///
+    /// # Return:
+    /// the power in the config of self, 1 if exp is 0, or None if the power can't be allocated
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_unsigned(&2, URL_SAFE_NO_PADDING); // Sets b64 to C
+    ///     match b64.checked_pow(12) {
+    ///         Some(value) => println!("{}", value), // Prints BAA which is 4096
+    ///         None => println!("Too large"),
+    ///     }
+    /// }
+    /// ```
+    pub fn checked_pow(&self, exp: u32) -> Option<Base64<'a>> {
+        let mut base: Vec<u128> = self.digits().collect();
+        let mut result: Vec<u128> = vec![1];
+        let mut exp = exp;
+        // Square and multiply
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = multiply_digits(&result, &base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = multiply_digits(&base, &base)?;
+            }
+        }
+        Some(self.number_with_digits(&result))
+    }
+
+    /// Adds 1 to the Base64 number digit by digit so it isn't limited to a u128, a digit is added
+    /// to the front when the most significant digit carries over. Line endings are removed
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned(&4095, URL_SAFE_NO_PADDING); // Sets b64 to __
+    ///     b64.increment();
+    ///     println!("{}", b64); // Prints BAA
+    /// }
+    /// ```
+    pub fn increment(&mut self) {
+        let mut digits: Vec<u128> = self.digits().collect();
+        let mut i = digits.len();
+        loop {
+            if i == 0 {
+                // Carried past the most significant digit
+                digits.insert(0, 1);
+                break;
+            }
+            i -= 1;
+            if digits[i] == 63 {
+                digits[i] = 0; // Carry to the next digit
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+        self.set_digits(&digits);
+    }
+
+    /// Subtracts 1 from the Base64 number digit by digit so it isn't limited to a u128, if the
+    /// number is 0 it stays 0. A most significant digit that borrows down to 0 is removed. Line
+    /// endings are removed
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned(&4096, URL_SAFE_NO_PADDING); // Sets b64 to BAA
+    ///     b64.decrement();
+    ///     println!("{}", b64); // Prints __
+    ///     let mut b64 = Base64::new_encode_unsigned(&0, URL_SAFE_NO_PADDING); // Sets b64 to A
+    ///     b64.decrement();
+    ///     println!("{}", b64); // Prints A
+    /// }
+    /// ```
+    pub fn decrement(&mut self) {
+        let mut digits: Vec<u128> = self.digits().collect();
+        if digits.iter().all(|d| *d == 0) {
+            // Saturate at 0
+            return;
+        }
+        for i in (0..digits.len()).rev() {
+            if digits[i] == 0 {
+                digits[i] = 63; // Borrow from the next digit
+            } else {
+                digits[i] -= 1;
+                if i == 0 && digits[i] == 0 && digits.len() > 1 {
+                    digits.remove(0);
+                }
+                break;
+            }
+        }
+        self.set_digits(&digits);
+    }
+
+    /// Appends a digit as the new least significant digit, this is the same as shifting left by 1
+    /// digit and adding the digit. Line endings are removed
+    ///
+    /// # Parameters:
+    /// digit, the value of the digit from 0 to 63
+    ///
+    /// # Return:
+    /// Result of either () or
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError) if the
+    /// digit is greater than 63
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::empty(URL_SAFE_NO_PADDING);
+    ///     b64.push_digit(1).unwrap();
+    ///     b64.push_digit(0).unwrap();
+    ///     println!("{}", b64); // Prints BA which is 64
+    ///     println!("{}", b64.push_digit(64).is_err()); // Prints true
+    /// }
+    /// ```
+    pub fn push_digit(&mut self, digit: u128) -> Result<(), Base64Error> {
+        if digit > 63 {
+            return Err(Base64Error::OverflowError);
+        }
+        let mut digits: Vec<u128> = self.digits().collect();
+        digits.push(digit);
+        self.set_digits(&digits);
+        Ok(())
+    }
+
+    /// Removes the least significant digit and returns its value, this is the same as shifting
+    /// right by 1 digit except the last digit is removed rather than becoming 0. Line endings are
+    /// removed
+    ///
+    /// # Return:
+    /// the value of the digit from 0 to 63 or None if there are no digits
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned(&65, URL_SAFE_NO_PADDING); // Sets b64 to BB
+    ///     println!("{:?}", b64.pop_digit()); // Prints Some(1)
+    ///     println!("{:?}", b64.pop_digit()); // Prints Some(1)
+    ///     println!("{:?}", b64.pop_digit()); // Prints None
+    /// }
+    /// ```
+    pub fn pop_digit(&mut self) -> Option<u128> {
+        let mut digits: Vec<u128> = self.digits().collect();
+        let digit = digits.pop()?;
+        self.set_digits(&digits);
+        Some(digit)
+    }
+
+    /// Shifts the Base64 number left by n digits by appending n 0 digits, this multiplies the
+    /// number by 64^n. Line endings are removed
+    ///
+    /// # Parameters:
+    /// n, the number of digits to shift by
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned(&1, URL_SAFE_NO_PADDING); // Sets b64 to B
+    ///     b64.shift_left(1);
+    ///     println!("{}", b64); // Prints BA which is 64
+    /// }
+    /// ```
+    pub fn shift_left(&mut self, n: usize) {
+        let mut digits: Vec<u128> = self.digits().collect();
+        digits.resize(digits.len() + n, 0);
+        self.set_digits(&digits);
+    }
+
+    /// Shifts the Base64 number right by n digits by removing the n least significant digits,
+    /// this divides the number by 64^n. If n is at least the number of digits the number becomes
+    /// 0. Line endings are removed
+    ///
+    /// # Parameters:
+    /// n, the number of digits to shift by
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned(&65, URL_SAFE_NO_PADDING); // Sets b64 to BB
+    ///     b64.shift_right(1);
+    ///     println!("{}", b64); // Prints B
+    ///     b64.shift_right(5);
+    ///     println!("{}", b64); // Prints A
+    /// }
+    /// ```
+    pub fn shift_right(&mut self, n: usize) {
+        let mut digits: Vec<u128> = self.digits().collect();
+        if n >= digits.len() {
+            digits = vec![0];
+        } else {
+            digits.truncate(digits.len() - n);
+        }
+        self.set_digits(&digits);
+    }
+
+    /// Computes the Base64 number modulo m one digit at a time, so unlike
+    /// [decode_to_unsigned](struct.Base64.html#method.decode_to_unsigned) it never overflows no
+    /// matter how many digits there are. Padding, line endings, and spaces are skipped
+    ///
+    /// # Parameters:
+    /// m, the modulus
+    ///
+    /// # Return:
+    /// the number modulo m, 0 if m is 0 instead of panicing
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_unsigned(&100, URL_SAFE_NO_PADDING); // Sets b64 to Bk
+    ///     println!("{}", b64.mod_u64(7)); // Prints 2
+    /// }
+    /// ```
+    pub fn mod_u64(&self, m: u64) -> u64 {
+        if m == 0 {
+            return 0;
+        }
+        let m = u128::from(m);
+        // acc is always less than m so acc * 64 + 63 fits in a u128
+        self.digits().fold(0, |acc, digit| (acc * 64 + digit) % m) as u64
+    }
+
+    /// Bitwise ANDs the decoded bytes of two Base64 numbers, the shorter bytes are left padded with
+    /// 0s to the length of the longer bytes
+    ///
+    /// # Parameters:
+    /// other, the Base64 number to AND with
+    ///
+    /// # Return:
+    /// the resulting bytes, which can be encoded with any config
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::STANDARD; // Constant config
+    ///
+    /// fn main() {
+    ///     let lhs = Base64::new_encode_bytes(&[0b1100, 0xFF], STANDARD);
+    ///     let rhs = Base64::new_encode_bytes(&[0b1010], STANDARD);
+    ///     println!("{:?}", lhs.bitand_bytes(&rhs)); // Prints [0, 10]
+    /// }
+    /// ```
+    pub fn bitand_bytes(&self, other: &Base64) -> Vec<u8> {
+        combine_bytes(&self.decode_to_bytes(), &other.decode_to_bytes(), |a, b| {
+            a & b
+        })
+    }
+
+    /// Bitwise ORs the decoded bytes of two Base64 numbers, the shorter bytes are left padded with
+    /// 0s to the length of the longer bytes
+    ///
+    /// # Parameters:
+    /// other, the Base64 number to OR with
+    ///
+    /// # Return:
+    /// the resulting bytes, which can be encoded with any config
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::STANDARD; // Constant config
+    ///
+    /// fn main() {
+    ///     let lhs = Base64::new_encode_bytes(&[0b1100, 0xF0], STANDARD);
+    ///     let rhs = Base64::new_encode_bytes(&[0b1010], STANDARD);
+    ///     println!("{:?}", lhs.bitor_bytes(&rhs)); // Prints [12, 250]
+    /// }
+    /// ```
+    pub fn bitor_bytes(&self, other: &Base64) -> Vec<u8> {
+        combine_bytes(&self.decode_to_bytes(), &other.decode_to_bytes(), |a, b| {
+            a | b
+        })
+    }
+
+    /// Bitwise XORs the decoded bytes of two Base64 numbers, the shorter bytes are left padded with
+    /// 0s to the length of the longer bytes
+    ///
+    /// # Parameters:
+    /// other, the Base64 number to XOR with
+    ///
+    /// # Return:
+    /// the resulting bytes, which can be encoded with any config
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::STANDARD; // Constant config
+    ///
+    /// fn main() {
+    ///     let lhs = Base64::new_encode_bytes(&[0b1100, 0xFF], STANDARD);
+    ///     let rhs = Base64::new_encode_bytes(&[0b1010], STANDARD);
+    ///     println!("{:?}", lhs.bitxor_bytes(&rhs)); // Prints [12, 245]
+    /// }
+    /// ```
+    pub fn bitxor_bytes(&self, other: &Base64) -> Vec<u8> {
+        combine_bytes(&self.decode_to_bytes(), &other.decode_to_bytes(), |a, b| {
+            a ^ b
+        })
+    }
+
+    /// Appends the digits of other after the digits of self, the result is
+    /// self * 64^(number of digits in other) + other. Padding and line endings are removed before
+    /// appending and padding is added again afterwards
+    ///
+    /// # Parameters:
+    /// other, the Base64 number whose digits are appended
+    ///
+    /// # Return:
+    /// Result of either the concatenated Base64 number or
+    /// [Base64Error::ConfigMismatchError](error/enum.Base64Error.html#variant.ConfigMismatchError)
+    /// if the configs are different
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let one = Base64::new_encode_unsigned(&1, URL_SAFE_NO_PADDING); // Sets one to B
+    ///     let zero = Base64::new_encode_unsigned(&0, URL_SAFE_NO_PADDING); // Sets zero to A
+    ///     match one.concat(&zero) {
+    ///         Ok(value) => println!("{}", value), // Prints BA which is 64
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn concat(&self, other: &Base64) -> Result<Base64<'a>, Base64Error> {
+        if self.conf != other.conf {
+            return Err(Base64Error::ConfigMismatchError);
+        }
+        let digits: Vec<u128> = self.digits().chain(other.digits()).collect();
+        Ok(self.with_digits(&digits))
+    }
+
+    /// Splits the Base64 number into the mid most significant digits and the remaining least
+    /// significant digits, both with the same config. Padding and line endings are removed before
+    /// splitting so mid only counts digits, and padding is added again to each part. If mid is 0
+    /// the first part is 0 and if mid is at least the number of digits the second part is 0
+    ///
+    /// # Parameters:
+    /// mid, the number of digits in the first part
+    ///
+    /// # Return:
+    /// the most significant part and the least significant part
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_from_string("abcd", URL_SAFE_NO_PADDING).unwrap();
+    ///     let (high, low) = b64.split_at(2);
+    ///     println!("{} {}", high, low); // Prints ab cd
+    ///     let (high, low) = b64.split_at(10);
+    ///     println!("{} {}", high, low); // Prints abcd A
+    /// }
+    /// ```
+    pub fn split_at(&self, mid: usize) -> (Base64<'a>, Base64<'a>) {
+        let digits: Vec<u128> = self.digits().collect();
+        let (high, low) = digits.split_at(mid.min(digits.len()));
+        (self.with_digits(high), self.with_digits(low))
+    }
+
+    /// Copies the digits in the range into a new Base64 number with the same config. Padding and
+    /// line endings are removed first so the range only counts digits, and padding is added again
+    /// afterwards. The end of the range is clamped to the number of digits instead of panicing and
+    /// an empty range is 0
+    ///
+    /// # Parameters:
+    /// range, the indexes of the digits to copy, index 0 being the most significant
+    ///
+    /// # Return:
+    /// the Base64 number of the digits in the range
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_from_string("abcdef", URL_SAFE_NO_PADDING).unwrap();
+    ///     println!("{}", b64.slice(1..4)); // Prints bcd
+    ///     println!("{}", b64.slice(2..100)); // Prints cdef
+    /// }
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> Base64<'a> {
+        let digits: Vec<u128> = self.digits().collect();
+        let end = range.end.min(digits.len());
+        let start = range.start.min(end);
+        self.with_digits(&digits[start..end])
+    }
+
+    /// Splits the digits into chunks of chunk_digits digits each with the same config, starting
+    /// at the most significant digit so only the last chunk can be shorter and joining the chunks
+    /// with [concat](struct.Base64.html#method.concat) gives back the same digits. Padding and
+    /// line endings are removed first so only digits are counted, and padding is added again to
+    /// each chunk
+    ///
+    /// # Parameters:
+    /// chunk_digits, the number of digits in each chunk, if it's 0 the whole number is one chunk
+    ///
+    /// # Return:
+    /// the chunks from the most significant to the least significant, empty if there are no
+    /// digits
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_from_string("abcde", URL_SAFE_NO_PADDING).unwrap();
+    ///     for chunk in b64.chunks(2) {
+    ///         println!("{}", chunk); // Prints ab, cd, e
+    ///     }
+    /// }
+    /// ```
+    pub fn chunks(&self, chunk_digits: usize) -> Vec<Base64<'a>> {
+        let digits: Vec<u128> = self.digits().collect();
+        if chunk_digits == 0 {
+            return vec![self.with_digits(&digits)];
+        }
+        digits
+            .chunks(chunk_digits)
+            .map(|chunk| self.with_digits(chunk))
+            .collect()
+    }
+
+    /// Reverses the order of the digits, padding and line endings are removed first so they
+    /// aren't moved to the front and padding is added again afterwards
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_from_string("abc", URL_SAFE_NO_PADDING).unwrap();
+    ///     b64.reverse();
+    ///     println!("{}", b64); // Prints cba
+    /// }
+    /// ```
+    pub fn reverse(&mut self) {
+        let mut digits: Vec<u128> = self.digits().collect();
+        digits.reverse();
+        self.set_digits(&digits);
+    }
+
+    /// Creates a Base64 number with the same config of the digits (0 to 63), no digits is 0
+    fn with_digits(&self, digits: &[u128]) -> Base64<'a> {
+        let mut b64 = Base64 {
+            value: String::new(),
+            conf: self.conf,
+            bytes: self.bytes,
+        };
+        if digits.is_empty() {
+            b64.set_digits(&[0]);
+        } else {
+            b64.set_digits(digits);
+        }
+        b64
+    }
+
+    /// Creates a Base64 number with the same config of the digits (0 to 63) that's a number rather
+    /// than encoded bytes, so it's never padded
+    fn number_with_digits(&self, digits: &[u128]) -> Base64<'a> {
+        let mut b64 = Base64 {
+            value: String::new(),
+            conf: self.conf,
+            bytes: false,
+        };
+        b64.set_digits(digits);
+        b64
+    }
+
+    /// Sets the value to the digits (0 to 63) and adds padding if necessary
+    fn set_digits(&mut self, digits: &[u128]) {
+        self.value = digits
+            .iter()
+            .filter_map(|d| decimal_to_base64_char(self.conf.get_character_set(), *d))
+            .collect();
+        self.add_padding();
+    }
+}
+
+/// Adds two Base64 numbers with [checked_add](struct.Base64.html#method.checked_add)
+///
+/// # Panics:
+/// If the configs differ or the sum overflows a u128, this is the only exception to never
+/// panicing, use [checked_add](struct.Base64.html#method.checked_add) to handle these cases
+impl<'a> Add for Base64<'a> {
+    type Output = Base64<'a>;
+
+    fn add(self, other: Base64<'a>) -> Base64<'a> {
+        match self.checked_add(&other) {
+            Some(sum) => sum,
+            None => panic!("Base64 addition overflowed or the configs are different"),
+        }
+    }
+}
+
+/// Multiplies two numbers of digits (0 to 63) with the most significant digit first, the product
+/// has no leading 0 digits. None if the product can't be allocated
+fn multiply_digits(lhs: &[u128], rhs: &[u128]) -> Option<Vec<u128>> {
+    let len = lhs.len() + rhs.len();
+    let mut product: Vec<u128> = Vec::new();
+    product.try_reserve_exact(len).ok()?;
+    product.resize(len, 0);
+    // Least significant digits first
+    for (i, a) in lhs.iter().rev().enumerate() {
+        let mut carry = 0;
+        for (j, b) in rhs.iter().rev().enumerate() {
+            let current = product[len - 1 - (i + j)] + a * b + carry;
+            product[len - 1 - (i + j)] = current % 64;
+            carry = current / 64;
+        }
+        let mut k = i + rhs.len();
+        while carry > 0 {
+            let current = product[len - 1 - k] + carry;
+            product[len - 1 - k] = current % 64;
+            carry = current / 64;
+            k += 1;
+        }
+    }
+    // Keep at least 1 digit for 0
+    let leading = product
+        .iter()
+        .position(|d| *d != 0)
+        .unwrap_or(len.max(1) - 1);
+    product.drain(..leading);
+    if product.is_empty() {
+        product.push(0);
+    }
+    Some(product)
+}
+
+/// Combines two byte slices with op, the shorter slice is left padded with 0s
+fn combine_bytes(lhs: &[u8], rhs: &[u8], op: impl Fn(u8, u8) -> u8) -> Vec<u8> {
+    let len = lhs.len().max(rhs.len());
+    let byte = |bytes: &[u8], i: usize| {
+        if i < len - bytes.len() {
+            0
+        } else {
+            bytes[i - (len - bytes.len())]
+        }
+    };
+    (0..len).map(|i| op(byte(lhs, i), byte(rhs, i))).collect()
+}
@@ -0,0 +1,55 @@
+use alloc::string::String;
+
+use super::{config, error::Base64Error, Base64};
+
+impl Base64<'static> {
+    /// Creates a Base64 number from a &str produced by other Base64 libraries such as the
+    /// `base64` crate with its standard engine. The &str is decoded to bytes with
+    /// [STANDARD](config/constant.STANDARD.html) and encoded again, so the value is always in
+    /// the canonical padded form. Requires the `compat` feature
+    ///
+    /// # Returns:
+    /// Result of either the new Base64 number or a
+    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::Base64;
+    ///
+    /// fn main() {
+    ///     match Base64::from_standard_base64("SGVsbG8=") {
+    ///         Ok(b64) => println!("{:?}", b64.decode_to_bytes()), // Prints the bytes of Hello
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn from_standard_base64(s: &str) -> Result<Base64<'static>, Base64Error> {
+        let bytes = super::decode_config(s, config::STANDARD)?;
+        Ok(Base64::new_encode_bytes(&bytes, config::STANDARD))
+    }
+}
+
+impl<'a> Base64<'a> {
+    /// Encodes the decoded bytes of the Base64 number with
+    /// [STANDARD](config/constant.STANDARD.html), the same output as other Base64 libraries such
+    /// as the `base64` crate with its standard engine. Requires the `compat` feature
+    ///
+    /// # Returns:
+    /// the padded standard Base64 String
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::Base64;
+    /// use lb64::config::URL_SAFE_NO_PADDING;
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes(&[251, 255], URL_SAFE_NO_PADDING); // Sets b64 to -_8
+    ///     println!("{}", b64.to_standard_base64()); // Prints +/8=
+    /// }
+    /// ```
+    pub fn to_standard_base64(&self) -> String {
+        super::encode_config(&self.decode_to_bytes(), config::STANDARD)
+    }
+}
@@ -1,6 +1,9 @@
-use std::cmp::PartialEq;
-use std::fmt::{Display, Formatter};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::PartialEq;
+use core::fmt::{self, Display, Formatter};
 
+use super::decimal_to_base64_char;
 use super::error::ConfigError;
 
 /// Configuration for Base64 number that consists of
@@ -12,14 +15,95 @@ use super::error::ConfigError;
 ///
 /// line_length: Optional maximum line length for the Base64 number
 ///
+/// line_ending: the characters that end a line when the line length is reached, defaults to
+/// [LineEnding::Lf](enum.LineEnding.html#variant.Lf)
+///
+/// ignore_whitespace: whether spaces and line endings are skipped when decoding instead of being
+/// invalid characters, defaults to true only if there's a line length
+///
+/// case_insensitive: whether ASCII letters match the character set ignoring case, defaults to
+/// false
+///
+/// require_padding_on_decode: whether strict decoding requires the number of characters to be
+/// divisible by 4 even when encoding leaves out padding, defaults to false
+///
 /// All characters must be graphically representable characters in [UTF8](https://www.utf8-chartable.de/unicode-utf8-table.pl)
 ///
 /// Implements Equals, Debug, and Clone
-#[derive(Eq, Debug, Clone)]
+#[derive(Eq, Clone)]
 pub struct Config<'a> {
     character_set: &'a [char],
     pad: Option<char>,
-    line_length: Option<u8>,
+    line_length: Option<usize>,
+    line_ending: LineEnding,
+    ignore_whitespace: bool,
+    case_insensitive: bool,
+    require_padding_on_decode: bool,
+    // Digit value of every ASCII character, NOT_A_DIGIT if it isn't in the character set
+    digits: [u8; 128],
+}
+
+/// Marks an ASCII character that isn't in the character set
+pub(crate) const NOT_A_DIGIT: u8 = 0xFF;
+
+/// Builds the table of the digit value of every ASCII character in the character set, if case
+/// insensitive the other case of each ASCII letter has the same digit value
+const fn digit_table(set: &[char], case_insensitive: bool) -> [u8; 128] {
+    let mut table = [NOT_A_DIGIT; 128];
+    let mut i = 0;
+    while i < set.len() {
+        let c = set[i];
+        if c.is_ascii() {
+            table[c as usize] = i as u8;
+            if case_insensitive && c.is_ascii_alphabetic() {
+                // Flip the case bit
+                table[(c as u8 ^ 0x20) as usize] = i as u8;
+            }
+        }
+        i += 1;
+    }
+    table
+}
+
+/// Checks if two characters in the character set are the same ASCII letter in different cases
+fn has_ambiguous_case(set: &[char]) -> bool {
+    set.iter()
+        .any(|c| c.is_ascii_lowercase() && set.contains(&c.to_ascii_uppercase()))
+}
+
+/// Line ending inserted into encoded Base64 when a config's line length is reached
+///
+/// Implements Equals, Debug, Clone, Copy, and Default which is
+/// [Lf](enum.LineEnding.html#variant.Lf)
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Default)]
+pub enum LineEnding {
+    /// A single newline '\n'
+    #[default]
+    Lf,
+    /// A carriage return followed by a newline "\r\n" as required by
+    /// [RFC 2045](https://tools.ietf.org/html/rfc2045)
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the characters of the line ending
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::LineEnding;
+    ///
+    /// fn main() {
+    ///     println!("{:?}", LineEnding::CrLf.as_str()); // Prints "\r\n"
+    /// }
+    /// ```
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
 }
 
 impl<'a> Config<'a> {
@@ -63,7 +147,7 @@ impl<'a> Config<'a> {
     pub fn new(
         set: &'a [char],
         pad_char: Option<char>,
-        len: Option<u8>,
+        len: Option<usize>,
     ) -> Result<Self, ConfigError> {
         if set.len() != 64 {
             Err(ConfigError::CharacterSetLengthError)
@@ -76,14 +160,104 @@ impl<'a> Config<'a> {
         } else if are_duplicates(set) {
             Err(ConfigError::DuplicateCharacterError)
         } else {
-            Ok(Self {
-                character_set: set,
-                pad: pad_char,
-                line_length: len,
-            })
+            Ok(Config::new_unchecked(
+                set,
+                pad_char,
+                len,
+                LineEnding::default(),
+            ))
         }
     }
 
+    /// Creates a config without validating it, used for the built-in configs
+    const fn new_unchecked(
+        set: &'a [char],
+        pad_char: Option<char>,
+        len: Option<usize>,
+        ending: LineEnding,
+    ) -> Self {
+        Config {
+            character_set: set,
+            pad: pad_char,
+            line_length: len,
+            line_ending: ending,
+            ignore_whitespace: len.is_some(),
+            case_insensitive: false,
+            require_padding_on_decode: false,
+            digits: digit_table(set, false),
+        }
+    }
+
+    /// Creates an [OwnedConfig](struct.OwnedConfig.html) from a &str where every char is a
+    /// character in the character set, multi-byte characters such as emoji count as a single
+    /// character
+    ///
+    /// # Parameters:
+    /// &str of the 64 characters in the character set
+    ///
+    /// Optional: padding for base64
+    ///
+    /// Optional: Fixed line length
+    ///
+    /// # Returns:
+    /// Result<OwnedConfig, base64::error::ConfigError> either the new owned config or the same
+    /// errors as [Config::new](struct.Config.html#method.new),
+    /// [CharacterSetLengthError](../error/enum.ConfigError.html#variant.CharacterSetLengthError)
+    /// if there aren't exactly 64 characters
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::Config;
+    /// use lb64::Base64;
+    ///
+    /// fn main() {
+    ///     let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    ///     match Config::from_alphabet_str(alphabet, None, None) {
+    ///         Ok(owned) => {
+    ///             let conf = owned.as_config();
+    ///             let b64 = Base64::new_encode_unsigned(&63, &conf);
+    ///             println!("{}", b64); // Prints _
+    ///         }
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn from_alphabet_str(
+        s: &str,
+        pad_char: Option<char>,
+        len: Option<usize>,
+    ) -> Result<OwnedConfig, ConfigError> {
+        OwnedConfig::from_alphabet_str(s, pad_char, len)
+    }
+
+    /// Creates a [ConfigBuilder](struct.ConfigBuilder.html) to construct a config without
+    /// positional arguments, padding and line length default to None
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::Config;
+    /// use lb64::error::ConfigError;
+    ///
+    /// fn main() -> Result<(), ConfigError> {
+    ///     let character_set = &[
+    ///     'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+    ///     'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
+    ///     'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+    ///     'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '-', '_',
+    /// ];
+    ///     let conf = Config::builder().alphabet(character_set).padding('=').build()?; // URL safe with padding
+    ///     println!("{}", conf);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn builder() -> ConfigBuilder<'a> {
+        ConfigBuilder::default()
+    }
+
     /// Sets the character set by the provided slice
     ///
     /// # Returns:
@@ -129,8 +303,11 @@ impl<'a> Config<'a> {
             Err(ConfigError::DuplicateCharacterError)
         } else if !character_set_is_representable(set) {
             Err(ConfigError::CharacterSetUnrepresentableCharacter)
+        } else if self.case_insensitive && has_ambiguous_case(set) {
+            Err(ConfigError::AmbiguousCaseError)
         } else {
             self.character_set = set;
+            self.digits = digit_table(set, self.case_insensitive);
             Ok(())
         }
     }
@@ -166,11 +343,14 @@ impl<'a> Config<'a> {
     ///     }
     /// }
     /// ```
-    pub fn get_line_length(&self) -> Option<u8> {
+    pub fn get_line_length(&self) -> Option<usize> {
         self.line_length
     }
 
-    /// Sets the maximum line length for a configuration
+    /// Sets the maximum line length for a configuration, this also sets whether whitespace is
+    /// ignored when decoding to whether there's a line length, use
+    /// [set_ignore_whitespace](struct.Config.html#method.set_ignore_whitespace) afterwards to
+    /// change it
     ///
     /// # Example:
     /// ```
@@ -194,8 +374,193 @@ impl<'a> Config<'a> {
     ///     }
     /// }
     /// ```
-    pub fn set_line_length(&mut self, len: Option<u8>) {
+    pub fn set_line_length(&mut self, len: Option<usize>) {
         self.line_length = len;
+        self.ignore_whitespace = len.is_some();
+    }
+
+    /// Return the line ending inserted when the line length is reached
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{LineEnding, MIME};
+    ///
+    /// fn main() {
+    ///     assert_eq!(MIME.get_line_ending(), LineEnding::CrLf);
+    /// }
+    /// ```
+    pub fn get_line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Sets the line ending inserted when the line length is reached
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{Config, LineEnding, MIME};
+    ///
+    /// fn main() {
+    ///     let mut conf: Config = MIME.clone();
+    ///     conf.set_line_ending(LineEnding::Lf); // MIME with only '\n' at the end of each line
+    /// }
+    /// ```
+    pub fn set_line_ending(&mut self, ending: LineEnding) {
+        self.line_ending = ending;
+    }
+
+    /// Return whether spaces and line endings are skipped when decoding, if false they're invalid
+    /// characters
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{MIME, URL_SAFE_NO_PADDING};
+    ///
+    /// fn main() {
+    ///     println!("{}", MIME.get_ignore_whitespace()); // Prints true
+    ///     println!("{}", URL_SAFE_NO_PADDING.get_ignore_whitespace()); // Prints false
+    /// }
+    /// ```
+    pub fn get_ignore_whitespace(&self) -> bool {
+        self.ignore_whitespace
+    }
+
+    /// Sets whether spaces and line endings are skipped when decoding, protocols that must not
+    /// allow anything to be smuggled in whitespace should set it to false
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{Config, MIME};
+    ///
+    /// fn main() {
+    ///     let mut conf: Config = MIME.clone();
+    ///     conf.set_ignore_whitespace(false); // MIME that rejects spaces and line endings
+    /// }
+    /// ```
+    pub fn set_ignore_whitespace(&mut self, ignore: bool) {
+        self.ignore_whitespace = ignore;
+    }
+
+    /// Return whether ASCII letters match the character set ignoring case when decoding
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::MIME;
+    ///
+    /// fn main() {
+    ///     println!("{}", MIME.get_case_insensitive()); // Prints false
+    /// }
+    /// ```
+    pub fn get_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Sets whether ASCII letters match the character set ignoring case, when true a letter in
+    /// either case is read as the digit of the letter in the character set and is stored in the
+    /// case of the character set. Encoding always uses the case of the character set
+    ///
+    /// # Returns:
+    /// Result<(), base64::error::ConfigError> the ConfigError is
+    /// [AmbiguousCaseError](../error/enum.ConfigError.html#variant.AmbiguousCaseError) if the
+    /// character set has the same letter in both cases, such as 'A' and 'a'
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{Config, MIME};
+    /// use lb64::Base64;
+    ///
+    /// fn main() {
+    ///     let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!#$%&()*+,-./:;<>?@[]^_{|}~'";
+    ///     let owned = Config::from_alphabet_str(alphabet, None, None).unwrap();
+    ///     let mut conf = owned.as_config();
+    ///     conf.set_case_insensitive(true).unwrap();
+    ///     let b64 = Base64::new_from_string("bA", &conf).unwrap();
+    ///     println!("{}", b64); // Prints BA
+    ///     let mut conf: Config = MIME.clone();
+    ///     println!("{}", conf.set_case_insensitive(true).is_err()); // Prints true
+    /// }
+    /// ```
+    pub fn set_case_insensitive(&mut self, case_insensitive: bool) -> Result<(), ConfigError> {
+        if case_insensitive && has_ambiguous_case(self.character_set) {
+            return Err(ConfigError::AmbiguousCaseError);
+        }
+        self.case_insensitive = case_insensitive;
+        self.digits = digit_table(self.character_set, case_insensitive);
+        Ok(())
+    }
+
+    /// Return whether [decode_to_bytes_strict](../struct.Base64.html#method.decode_to_bytes_strict)
+    /// requires the number of digits and padding to be divisible by 4
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::URL_SAFE_NO_PADDING;
+    ///
+    /// fn main() {
+    ///     println!("{}", URL_SAFE_NO_PADDING.get_require_padding_on_decode()); // Prints false
+    /// }
+    /// ```
+    pub fn get_require_padding_on_decode(&self) -> bool {
+        self.require_padding_on_decode
+    }
+
+    /// Sets whether [decode_to_bytes_strict](../struct.Base64.html#method.decode_to_bytes_strict)
+    /// requires the number of digits and padding to be divisible by 4, for systems that encode
+    /// without padding but only decode padded values. Encoding isn't changed
+    ///
+    /// With padding strict decoding already requires the exact amount of padding so this doesn't
+    /// change anything. Without padding (pad None) there's no padding character to complete the
+    /// last group, so only values whose number of digits is divisible by 4, the encodings of a
+    /// multiple of 3 bytes, can be decoded strictly
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{Config, URL_SAFE_NO_PADDING};
+    /// use lb64::Base64;
+    ///
+    /// fn main() {
+    ///     let mut conf: Config = URL_SAFE_NO_PADDING.clone();
+    ///     conf.set_require_padding_on_decode(true);
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), &conf); // Sets b64 to SGk
+    ///     println!("{}", b64.decode_to_bytes_strict().is_err()); // Prints true
+    ///     let b64 = Base64::new_encode_bytes("Hi!".as_bytes(), &conf); // Sets b64 to SGkh
+    ///     println!("{}", b64.decode_to_bytes_strict().is_ok()); // Prints true
+    /// }
+    /// ```
+    pub fn set_require_padding_on_decode(&mut self, require: bool) {
+        self.require_padding_on_decode = require;
+    }
+
+    /// The character in the character set the character matches, so letters in the other case are
+    /// stored in the case of the character set when case insensitive. Other characters are
+    /// returned unchanged
+    pub(crate) fn canonical(&self, c: char) -> char {
+        if self.case_insensitive {
+            if let Some(digit) = self.digit_of(c) {
+                return self.character_set[digit as usize];
+            }
+        }
+        c
+    }
+
+    /// Whether the character is a space or line ending that's skipped when decoding
+    pub(crate) fn is_ignored_whitespace(&self, c: char) -> bool {
+        self.ignore_whitespace && (c == ' ' || c == '\n' || c == '\r')
     }
 
     /// Return Padding character
@@ -217,6 +582,140 @@ impl<'a> Config<'a> {
         self.pad
     }
 
+    /// Checks if the character is in the character set, the padding character isn't
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::MIME;
+    ///
+    /// fn main() {
+    ///     println!("{}", MIME.contains('+')); // Prints true
+    ///     println!("{}", MIME.contains('=')); // Prints false
+    ///     println!("{}", MIME.contains('-')); // Prints false
+    /// }
+    /// ```
+    pub fn contains(&self, c: char) -> bool {
+        self.digit_of(c).is_some()
+    }
+
+    /// Converts a digit (0 to 63) to its character in the character set
+    ///
+    /// # Return:
+    /// the character of the digit, None if the digit is greater than 63
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::MIME;
+    ///
+    /// fn main() {
+    ///     println!("{:?}", MIME.digit_to_char(62)); // Prints Some('+')
+    ///     println!("{:?}", MIME.digit_to_char(64)); // Prints None
+    /// }
+    /// ```
+    pub fn digit_to_char(&self, digit: u128) -> Option<char> {
+        decimal_to_base64_char(self.character_set, digit)
+    }
+
+    /// Converts a character of the character set to its digit (0 to 63), if the config is case
+    /// insensitive either case of a letter is converted
+    ///
+    /// # Return:
+    /// the digit of the character, None if it isn't in the character set
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::MIME;
+    ///
+    /// fn main() {
+    ///     println!("{:?}", MIME.char_to_digit('+')); // Prints Some(62)
+    ///     println!("{:?}", MIME.char_to_digit('=')); // Prints None
+    /// }
+    /// ```
+    pub fn char_to_digit(&self, c: char) -> Option<u128> {
+        self.digit_of(c)
+    }
+
+    /// Checks if the character is the padding character, always false if there's no padding
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{MIME, URL_SAFE_NO_PADDING};
+    ///
+    /// fn main() {
+    ///     println!("{}", MIME.is_padding('=')); // Prints true
+    ///     println!("{}", URL_SAFE_NO_PADDING.is_padding('=')); // Prints false
+    /// }
+    /// ```
+    pub fn is_padding(&self, c: char) -> bool {
+        self.pad == Some(c)
+    }
+
+    /// Checks if the character set of other only differs from this one by the characters of some
+    /// digits, every character both character sets have is the same digit in both. So converting
+    /// with [set_config](../struct.Base64.html#method.set_config) only changes the characters
+    /// that aren't shared, for example STANDARD and URL_SAFE only differ at 62 and 63, but BCRYPT
+    /// puts 'A' at 2 instead of 0 so every digit moves
+    ///
+    /// # Return:
+    /// true if both character sets have the same length and no shared character has a different
+    /// digit
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{BCRYPT, STANDARD, URL_SAFE_NO_PADDING};
+    ///
+    /// fn main() {
+    ///     println!("{}", STANDARD.is_glyph_relabel_of(URL_SAFE_NO_PADDING)); // Prints true
+    ///     println!("{}", STANDARD.is_glyph_relabel_of(BCRYPT)); // Prints false
+    /// }
+    /// ```
+    pub fn is_glyph_relabel_of(&self, other: &Config) -> bool {
+        self.character_set.len() == other.character_set.len()
+            && self.character_set.iter().enumerate().all(|(i, c)| {
+                other
+                    .digit_of(*c)
+                    .filter(|digit| *digit != i as u128)
+                    .is_none()
+            })
+    }
+
+    /// Table of the digit value of every ASCII character, NOT_A_DIGIT if it isn't in the
+    /// character set
+    pub(crate) fn ascii_digits(&self) -> &[u8; 128] {
+        &self.digits
+    }
+
+    /// Digit value (0 to 63) of the character, ASCII characters are found with a table built when
+    /// the config was created
+    pub(crate) fn digit_of(&self, c: char) -> Option<u128> {
+        if c.is_ascii() {
+            match self.digits[c as usize] {
+                NOT_A_DIGIT => None,
+                digit => Some(u128::from(digit)),
+            }
+        } else {
+            self.character_set
+                .iter()
+                .position(|x| *x == c)
+                .map(|i| i as u128)
+        }
+    }
+
+    /// Reference to the padding character
+    pub(crate) fn padding_ref(&self) -> Option<&char> {
+        self.pad.as_ref()
+    }
+
     /// Sets the padding character
     ///
     /// # Returns:
@@ -251,7 +750,7 @@ impl<'a> Config<'a> {
     pub fn set_padding(&mut self, pad_char: Option<char>) -> Result<(), ConfigError> {
         if pad_char.is_some() && !check_unique_pad(self.character_set, pad_char.unwrap()) {
             Err(ConfigError::NotUniquePaddingError)
-        } else if pad_char.is_some() && is_representable(pad_char.unwrap()) {
+        } else if pad_char.is_some() && !is_representable(pad_char.unwrap()) {
             Err(ConfigError::PaddingUnrepresentableCharacter)
         } else {
             self.pad = pad_char;
@@ -260,6 +759,208 @@ impl<'a> Config<'a> {
     }
 }
 
+/// Builder for a [Config](struct.Config.html) created by
+/// [Config::builder](struct.Config.html#method.builder)
+///
+/// Implements Default, Debug, and Clone
+#[derive(Default, Debug, Clone)]
+pub struct ConfigBuilder<'a> {
+    character_set: &'a [char],
+    pad: Option<char>,
+    line_length: Option<usize>,
+    line_ending: LineEnding,
+}
+
+impl<'a> ConfigBuilder<'a> {
+    /// Sets the character set of the config being built
+    pub fn alphabet(mut self, set: &'a [char]) -> Self {
+        self.character_set = set;
+        self
+    }
+
+    /// Sets the padding character of the config being built
+    pub fn padding(mut self, pad_char: char) -> Self {
+        self.pad = Some(pad_char);
+        self
+    }
+
+    /// Sets the maximum line length of the config being built
+    pub fn line_length(mut self, len: usize) -> Self {
+        self.line_length = Some(len);
+        self
+    }
+
+    /// Sets the line ending of the config being built
+    pub fn line_ending(mut self, ending: LineEnding) -> Self {
+        self.line_ending = ending;
+        self
+    }
+
+    /// Creates the config with the same validation as [Config::new](struct.Config.html#method.new)
+    ///
+    /// # Returns:
+    /// Result<Config, base64::error::ConfigError> either the new config or the same errors as
+    /// [Config::new](struct.Config.html#method.new)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{Config, LineEnding, MIME};
+    ///
+    /// fn main() {
+    ///     match Config::builder()
+    ///         .alphabet(MIME.get_character_set())
+    ///         .padding('=')
+    ///         .line_length(76)
+    ///         .line_ending(LineEnding::CrLf)
+    ///         .build()
+    ///     {
+    ///         Ok(conf) => println!("{}", conf), // Same as MIME
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn build(self) -> Result<Config<'a>, ConfigError> {
+        let mut conf = Config::new(self.character_set, self.pad, self.line_length)?;
+        conf.line_ending = self.line_ending;
+        Ok(conf)
+    }
+}
+
+/// Configuration for Base64 number that owns it's character set instead of borrowing it, useful
+/// for character sets created at runtime
+///
+/// character_set: the characters the Base64 number can have. First character provided is given
+/// value 0 and so on until the 64th character which is value 63
+///
+/// pad: Optional padding character for the Base64 number
+///
+/// line_length: Optional maximum line length for the Base64 number
+///
+/// line_ending: the characters that end a line when the line length is reached
+///
+/// Implements Equals, Debug, and Clone
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct OwnedConfig {
+    character_set: Vec<char>,
+    pad: Option<char>,
+    line_length: Option<usize>,
+    line_ending: LineEnding,
+}
+
+impl OwnedConfig {
+    /// Creates an owned config with provided values, with the same validation as
+    /// [Config::new](struct.Config.html#method.new)
+    ///
+    /// # Parameters:
+    /// Character set of the base64 values
+    ///
+    /// Optional: padding for base64
+    ///
+    /// Optional: Fixed line length
+    ///
+    /// # Returns:
+    /// Result<Self, base64::error::ConfigError> either the new owned config or the same errors as
+    /// [Config::new](struct.Config.html#method.new)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::OwnedConfig;
+    ///
+    /// fn main() {
+    ///     let character_set: Vec<char> = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/".chars().collect();
+    ///     match OwnedConfig::new(character_set, Some('='), None) {
+    ///         Ok(conf) => println!("Successful"),
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    #[allow(clippy::question_mark)]
+    pub fn new(
+        set: Vec<char>,
+        pad_char: Option<char>,
+        len: Option<usize>,
+    ) -> Result<Self, ConfigError> {
+        if let Err(e) = Config::new(&set, pad_char, len) {
+            return Err(e);
+        }
+        Ok(OwnedConfig {
+            character_set: set,
+            pad: pad_char,
+            line_length: len,
+            line_ending: LineEnding::default(),
+        })
+    }
+
+    /// Creates an owned config from a &str where every char is a character in the character
+    /// set
+    ///
+    /// # Returns:
+    /// Result<Self, base64::error::ConfigError> either the new owned config or the same errors as
+    /// [Config::new](struct.Config.html#method.new)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::OwnedConfig;
+    ///
+    /// fn main() {
+    ///     let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    ///     match OwnedConfig::from_alphabet_str(alphabet, None, None) {
+    ///         Ok(conf) => println!("Successful"),
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn from_alphabet_str(
+        s: &str,
+        pad_char: Option<char>,
+        len: Option<usize>,
+    ) -> Result<Self, ConfigError> {
+        OwnedConfig::new(s.chars().collect(), pad_char, len)
+    }
+
+    /// Borrows the owned config as a [Config](struct.Config.html) in order to create Base64
+    /// numbers
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::OwnedConfig;
+    /// use lb64::Base64;
+    ///
+    /// fn main() {
+    ///     let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    ///     match OwnedConfig::from_alphabet_str(alphabet, None, None) {
+    ///         Ok(owned) => {
+    ///             let conf = owned.as_config();
+    ///             let b64 = Base64::new_encode_unsigned(&63, &conf);
+    ///             println!("{}", b64); // Prints _
+    ///         }
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn as_config(&self) -> Config<'_> {
+        Config::new_unchecked(
+            &self.character_set,
+            self.pad,
+            self.line_length,
+            self.line_ending,
+        )
+    }
+
+    /// Sets the line ending inserted when the line length is reached
+    pub fn set_line_ending(&mut self, ending: LineEnding) {
+        self.line_ending = ending;
+    }
+}
+
 /// `MIME` compliant configuration as specified in [RFC 2045](https://tools.ietf.org/html/rfc2045)
 ///
 /// # Specifics:
@@ -270,6 +971,8 @@ impl<'a> Config<'a> {
 ///
 /// Maximum Line Length: 76
 ///
+/// Line Ending: \r\n
+///
 /// # Example:
 /// ```
 /// extern crate lb64;
@@ -284,16 +987,57 @@ impl<'a> Config<'a> {
 /// }
 /// ```
 pub const MIME: &Config = {
-    &Config {
-        character_set: &[
+    &Config::new_unchecked(
+        &[
             'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
             'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
             'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
             'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '/',
         ],
-        pad: Some('='),
-        line_length: Some(76),
-    }
+        Some('='),
+        Some(76),
+        LineEnding::CrLf,
+    )
+};
+
+/// `PEM` compliant configuration as specified in [RFC 7468](https://tools.ietf.org/html/rfc7468)
+/// and [RFC 1421](https://tools.ietf.org/html/rfc1421), also known as Radix-64
+///
+/// # Specifics:
+///
+/// Character Set: [A-Z], [a-z], [0-9], +, /
+///
+/// Padding Character: =
+///
+/// Maximum Line Length: 64
+///
+/// Line Ending: \n
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::{config, Base64};
+///
+/// fn main() {
+///     let b64 = Base64::new_encode_unsigned(&63, config::PEM); // Creates a PEM Compliant b64 of value 63
+///     println!("{}", config::PEM);
+///     println!("{}", b64);
+///     // Prints:
+///     // "/"
+/// }
+/// ```
+pub const PEM: &Config = {
+    &Config::new_unchecked(
+        &[
+            'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+            'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
+            'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+            'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '/',
+        ],
+        Some('='),
+        Some(64),
+        LineEnding::Lf,
+    )
 };
 
 /// `IMAP` compliant configuration as specified in [RFC 3501](https://tools.ietf.org/html/rfc3501)
@@ -320,16 +1064,17 @@ pub const MIME: &Config = {
 /// }
 /// ```
 pub const IMAP: &Config = {
-    &Config {
-        character_set: &[
+    &Config::new_unchecked(
+        &[
             'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
             'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
             'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
             'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', ',',
         ],
-        pad: None,
-        line_length: None,
-    }
+        None,
+        None,
+        LineEnding::Lf,
+    )
 };
 
 /// `Base64url` with padding compliant configuration as specified in [RFC 4648](https://tools.ietf.org/html/rfc4648#section-5)
@@ -358,16 +1103,17 @@ pub const IMAP: &Config = {
 /// }
 /// ```
 pub const URL_SAFE_PADDING: &Config = {
-    &Config {
-        character_set: &[
+    &Config::new_unchecked(
+        &[
             'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
             'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
             'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
             'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '-', '_',
         ],
-        pad: Some('='),
-        line_length: None,
-    }
+        Some('='),
+        None,
+        LineEnding::Lf,
+    )
 };
 
 /// `Base64url` without padding compliant configuration as specified in [RFC 4648](https://tools.ietf.org/html/rfc4648#section-5)
@@ -396,16 +1142,17 @@ pub const URL_SAFE_PADDING: &Config = {
 /// }
 /// ```
 pub const URL_SAFE_NO_PADDING: &Config = {
-    &Config {
-        character_set: &[
+    &Config::new_unchecked(
+        &[
             'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
             'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
             'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
             'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '-', '_',
         ],
-        pad: None,
-        line_length: None,
-    }
+        None,
+        None,
+        LineEnding::Lf,
+    )
 };
 
 /// Base64 `standard` compliant configuration as specified in [RFC 4648](https://tools.ietf.org/html/rfc4648)
@@ -430,28 +1177,271 @@ pub const URL_SAFE_NO_PADDING: &Config = {
 /// }
 /// ```
 pub const STANDARD: &Config = {
-    &Config {
-        character_set: &[
+    &Config::new_unchecked(
+        &[
             'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
             'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
             'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
             'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '/',
         ],
-        pad: Some('='),
-        line_length: None,
-    }
+        Some('='),
+        None,
+        LineEnding::Lf,
+    )
+};
+
+/// `bcrypt` configuration used by the [bcrypt](https://en.wikipedia.org/wiki/Bcrypt) password
+/// hash for its salt and hash
+///
+/// # Specifics:
+///
+/// Character Set: ., /, [A-Z], [a-z], [0-9]
+///
+/// Padding Character: None
+///
+/// Maximum Line Length: None
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::{config, Base64};
+///
+/// fn main() {
+///     let b64 = Base64::new_encode_bytes("Hello, World".as_bytes(), config::BCRYPT);
+///     println!("{}", b64);
+///     // Prints:
+///     // "QETqZE6qGDbtakvi"
+/// }
+/// ```
+pub const BCRYPT: &Config = {
+    &Config::new_unchecked(
+        &[
+            '.', '/', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+            'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f',
+            'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+            'x', 'y', 'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+        ],
+        None,
+        None,
+        LineEnding::Lf,
+    )
 };
 
+/// `crypt(3)` configuration used by traditional Unix
+/// [crypt](https://en.wikipedia.org/wiki/Crypt_(C)) password hashes. The bits are grouped the same
+/// as every other config, hashes such as MD5-crypt that group bytes in a different order need to
+/// reorder the bytes before encoding
+///
+/// # Specifics:
+///
+/// Character Set: ., /, [0-9], [A-Z], [a-z]
+///
+/// Padding Character: None
+///
+/// Maximum Line Length: None
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::{config, Base64};
+///
+/// fn main() {
+///     let b64 = Base64::new_encode_bytes("Hello, World".as_bytes(), config::CRYPT);
+///     println!("{}", b64);
+///     // Prints:
+///     // "G4JgP4wg63RjQalY"
+/// }
+/// ```
+pub const CRYPT: &Config = {
+    &Config::new_unchecked(
+        &[
+            '.', '/', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E',
+            'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V',
+            'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+            'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+        ],
+        None,
+        None,
+        LineEnding::Lf,
+    )
+};
+
+/// `Y64` configuration popularized by the YUI library, see
+/// [variants](https://en.wikipedia.org/wiki/Base64#Variants_summary_table), that's safe for URLs,
+/// cookies, and file names. Unlike `Base64url` the padding character is `-`,
+/// which is allowed because `-` isn't in the character set
+///
+/// # Specifics:
+///
+/// Character Set: [A-Z], [a-z], [0-9], ., _
+///
+/// Padding Character: -
+///
+/// Maximum Line Length: None
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::{config, Base64};
+///
+/// fn main() {
+///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), config::Y64);
+///     println!("{}", b64);
+///     // Prints:
+///     // "SGk-"
+/// }
+/// ```
+pub const Y64: &Config = {
+    &Config::new_unchecked(
+        &[
+            'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+            'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
+            'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+            'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '.', '_',
+        ],
+        Some('-'),
+        None,
+        LineEnding::Lf,
+    )
+};
+
+/// `XML_NMTOKEN` configuration for XML name tokens (Nmtoken), see
+/// [variants](https://en.wikipedia.org/wiki/Base64#Variants_summary_table), every character is
+/// allowed in an Nmtoken as defined by the [XML 1.0](https://www.w3.org/TR/xml/#NT-Nmtoken)
+/// specification
+///
+/// # Specifics:
+///
+/// Character Set: [A-Z], [a-z], [0-9], ., -
+///
+/// Padding Character: None
+///
+/// Maximum Line Length: None
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::{config, Base64};
+///
+/// fn main() {
+///     let b64 = Base64::new_encode_bytes(&[0xFB, 0xFF], config::XML_NMTOKEN);
+///     println!("{}", b64);
+///     // Prints:
+///     // ".-8"
+/// }
+/// ```
+pub const XML_NMTOKEN: &Config = {
+    &Config::new_unchecked(
+        &[
+            'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+            'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
+            'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+            'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '.', '-',
+        ],
+        None,
+        None,
+        LineEnding::Lf,
+    )
+};
+
+/// Finds every built-in config, `STANDARD`, `MIME`, `IMAP`, and `URLSAFE` with and without
+/// padding, whose character set and padding contain every character of the &str. Whitespace is
+/// ignored
+///
+/// Only those five configs are tried, `PEM`, `BCRYPT`, `CRYPT`, `Y64`, and `XML_NMTOKEN` are never
+/// returned. `PEM` has the same characters as `MIME` and the others reuse the same letters and
+/// digits for different values, so most strings would match them without telling them apart
+///
+/// # Parameters:
+/// s, the Base64 &str to check
+///
+/// # Returns:
+/// the built-in configs that can hold s, in the order STANDARD, MIME, IMAP, URL_SAFE_PADDING, and
+/// URL_SAFE_NO_PADDING
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+///
+/// use lb64::config::{detect_config, URL_SAFE_NO_PADDING, URL_SAFE_PADDING};
+///
+/// fn main() {
+///     let configs = detect_config("a-b_");
+///     assert_eq!(configs, vec![URL_SAFE_PADDING, URL_SAFE_NO_PADDING]);
+/// }
+/// ```
+pub fn detect_config(s: &str) -> Vec<&'static Config<'static>> {
+    [STANDARD, MIME, IMAP, URL_SAFE_PADDING, URL_SAFE_NO_PADDING]
+        .iter()
+        .filter(|conf| {
+            s.chars()
+                .filter(|c| !c.is_whitespace())
+                .all(|c| conf.is_padding(c) || conf.contains(c))
+        })
+        .cloned()
+        .collect()
+}
+
+impl Default for Config<'static> {
+    /// Creates a config equal to [STANDARD](constant.STANDARD.html), the character set is the
+    /// same 'static slice so nothing is allocated
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    ///
+    /// use lb64::config::{Config, STANDARD};
+    ///
+    /// fn main() {
+    ///     let conf = Config::default();
+    ///     assert_eq!(conf, *STANDARD);
+    /// }
+    /// ```
+    fn default() -> Self {
+        STANDARD.clone()
+    }
+}
+
 impl<'a> PartialEq for Config<'a> {
     fn eq(&self, other: &Config) -> bool {
         self.character_set == other.character_set
             && self.pad == other.pad
             && self.line_length == other.line_length
+            && self.line_ending == other.line_ending
+            && self.ignore_whitespace == other.ignore_whitespace
+            && self.case_insensitive == other.case_insensitive
+            && self.require_padding_on_decode == other.require_padding_on_decode
+    }
+}
+
+impl<'a> fmt::Debug for Config<'a> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        // Only the ends of the character set are shown to keep logs short, the digit table is
+        // derived from the character set so it's left out
+        let set = self.character_set;
+        let fingerprint: String = if set.len() > 8 {
+            let mut fingerprint: String = set[..4].iter().collect();
+            fingerprint.push_str("..");
+            fingerprint.extend(&set[set.len() - 4..]);
+            fingerprint
+        } else {
+            set.iter().collect()
+        };
+        f.debug_struct("Config")
+            .field("character_set", &fingerprint)
+            .field("pad", &self.pad)
+            .field("line_length", &self.line_length)
+            .field("line_ending", &self.line_ending)
+            .field("ignore_whitespace", &self.ignore_whitespace)
+            .field("case_insensitive", &self.case_insensitive)
+            .field("require_padding_on_decode", &self.require_padding_on_decode)
+            .finish()
     }
 }
 
 impl<'a> Display for Config<'a> {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    #[allow(clippy::needless_borrow)]
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         let mut print: String = String::new();
         for c in self.character_set.iter() {
             print.push(*c);
@@ -1,4 +1,9 @@
-use super::{base64_char_to_decimal, config::Config, error::Base64Error, Base64};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use super::encode::convert_u8_to_binary_string;
+use super::{config::Config, error::Base64Error, Base64, ByteOrder};
 
 impl<'a> Base64<'a> {
     /// Decode a Base64 value to it's a Vector of u8
@@ -22,15 +27,360 @@ impl<'a> Base64<'a> {
     /// }
     /// ```
     pub fn decode_to_bytes(&self) -> Vec<u8> {
-        decode_bytes(self.conf, &self.to_string())
+        self.try_decode_to_bytes().unwrap_or_default()
+    }
+
+    /// Decode a Base64 value to a Vector of u8 in the provided byte order, with
+    /// [LittleEndian](enum.ByteOrder.html#variant.LittleEndian) the decoded bytes are reversed.
+    /// This undoes [new_encode_bytes_with_order](struct.Base64.html#method.new_encode_bytes_with_order)
+    /// with the same order
+    ///
+    /// # Return:
+    /// The vector of u8 corresponding to the data that was encoded into base64, empty if the
+    /// value isn't valid
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, ByteOrder, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes_with_order(&[1, 2, 3], STANDARD, ByteOrder::LittleEndian);
+    ///     println!("{:?}", b64.decode_to_bytes_with_order(ByteOrder::LittleEndian)); // Prints [1, 2, 3]
+    ///     println!("{:?}", b64.decode_to_bytes()); // Prints [3, 2, 1]
+    /// }
+    /// ```
+    pub fn decode_to_bytes_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut bytes = self.decode_to_bytes();
+        if order == ByteOrder::LittleEndian {
+            bytes.reverse();
+        }
+        bytes
+    }
+
+    /// Decode the Base64 value into the provided buffer without allocating, use
+    /// [decoded_len](struct.Base64.html#method.decoded_len) to size the buffer. Bytes after the
+    /// decoded bytes are left as they are
+    ///
+    /// # Parameters:
+    /// out, the buffer to write the decoded bytes to
+    ///
+    /// # Return:
+    /// Result of either the number of bytes written,
+    /// [Base64Error::BufferTooSmall](error/enum.Base64Error.html#variant.BufferTooSmall) if out
+    /// is shorter than [decoded_len](struct.Base64.html#method.decoded_len), or
+    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_bytes("Hello".as_bytes(), STANDARD);
+    ///     let mut out = [0u8; 16];
+    ///     match b64.decode_to_slice(&mut out) {
+    ///         Ok(written) => println!("{:?}", &out[..written]), // Prints the bytes of Hello
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_to_slice(&self, out: &mut [u8]) -> Result<usize, Base64Error> {
+        if out.len() < self.decoded_len() {
+            return Err(Base64Error::BufferTooSmall);
+        }
+        let mut written = 0;
+        for (slot, byte) in out
+            .iter_mut()
+            .zip(DecodeIter::new(self.value.chars(), self.conf))
+        {
+            *slot = byte?;
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// Decode the Base64 value to bytes and convert them to a String
+    ///
+    /// # Return:
+    /// Result of either the String,
+    /// [Base64Error::InvalidUtf8](error/enum.Base64Error.html#variant.InvalidUtf8) if the bytes
+    /// aren't valid UTF-8, or
+    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_bytes("Hello".as_bytes(), STANDARD);
+    ///     match b64.decode_to_string() {
+    ///         Ok(value) => println!("{}", value), // Prints Hello
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_to_string(&self) -> Result<String, Base64Error> {
+        String::from_utf8(self.try_decode_to_bytes()?).map_err(|_| Base64Error::InvalidUtf8)
+    }
+
+    /// Compares the decoded bytes of two Base64 numbers, so padding, line endings, and the config
+    /// don't matter unlike == which compares the characters exactly
+    ///
+    /// # Parameters:
+    /// other, the Base64 number to compare with
+    ///
+    /// # Return:
+    /// true if both decode to the same bytes
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::MIME};
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME); // Sets b64 to SGk=
+    ///     let unpadded = b64.without_padding(); // SGk
+    ///     println!("{}", b64 == unpadded); // Prints false
+    ///     println!("{}", b64.eq_bytes(&unpadded)); // Prints true
+    /// }
+    /// ```
+    pub fn eq_bytes(&self, other: &Base64) -> bool {
+        self.decode_to_bytes() == other.decode_to_bytes()
+    }
+
+    /// Decode a Base64 value to it's a Vector of u8, checking that every character is valid
+    ///
+    /// # Return:
+    /// Result with either the vector of u8 corresponding to the data that was encoded into base64
+    /// or [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    /// if a character isn't in the character set, padding, a newline, or a space
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let word: &str = "Hello";
+    ///     let b64 =  Base64::new_encode_bytes(word.as_bytes(), STANDARD);
+    ///     match b64.try_decode_to_bytes() {
+    ///         Ok(value) => println!("{:?}", value),
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn try_decode_to_bytes(&self) -> Result<Vec<u8>, Base64Error> {
+        decode_bytes(self.conf, &self.value)
+    }
+
+    /// Decodes the Base64 value to bytes without branching on the value of any character, to
+    /// reduce timing side channels when decoding secrets such as keys and tokens. Digits are
+    /// looked up in the config's table of ASCII characters instead of searching the character
+    /// set, and padding, line endings, and spaces are masked out instead of skipped
+    ///
+    /// Only ASCII character sets, such as all of the built-in configs, are decoded in constant
+    /// time. Other character sets, for example emoji, are decoded with
+    /// [try_decode_to_bytes](struct.Base64.html#method.try_decode_to_bytes) and don't have the
+    /// guarantee. Invalid values aren't protected either, the invalid character is found the same
+    /// way as try_decode_to_bytes
+    ///
+    /// # Return:
+    /// Result of either the decoded bytes or a
+    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::MIME; // Include MIME config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Secret".as_bytes(), MIME);
+    ///     match b64.decode_to_bytes_ct() {
+    ///         Ok(bytes) => println!("{:?}", bytes), // Prints the bytes of Secret
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_to_bytes_ct(&self) -> Result<Vec<u8>, Base64Error> {
+        if !self.conf.get_character_set().iter().all(char::is_ascii) {
+            // The table only holds ASCII characters
+            return self.try_decode_to_bytes();
+        }
+        let table = self.conf.ascii_digits();
+        // u32::MAX never matches a character
+        let pad = self.conf.get_padding().map_or(u32::MAX, u32::from);
+        let ignore_whitespace = u32::from(self.conf.is_ignored_whitespace(' '));
+        // Every byte is written to the next position, which only moves past it if the byte is
+        // complete, so nothing is pushed conditionally
+        let mut v: Vec<u8> = alloc::vec![0; self.value.len() / 4 * 3 + 3];
+        let mut len = 0;
+        let mut buffer: u32 = 0;
+        let mut bits: u32 = 0;
+        let mut invalid: u32 = 0;
+        for c in self.value.chars() {
+            let code = u32::from(c);
+            let whitespace = ct_eq(code, u32::from(' '))
+                | ct_eq(code, u32::from('\n'))
+                | ct_eq(code, u32::from('\r'));
+            let skip = ct_eq(code, pad) | (whitespace & ignore_whitespace);
+            let keep = skip ^ 1;
+            // Characters above the table are never digits
+            let above = ct_eq(code >> 7, 0) ^ 1;
+            let digit = u32::from(table[(code & 0x7F) as usize]) | (above.wrapping_neg() & 0xFF);
+            invalid |= (digit >> 7) & keep;
+            let mask = keep.wrapping_neg();
+            buffer = (((buffer << 6) | (digit & 0x3F)) & mask) | (buffer & !mask);
+            bits += 6 & mask;
+            let complete = bits >> 3;
+            v[len] = (buffer >> (bits.wrapping_sub(8) & 0x1F)) as u8;
+            len += complete as usize;
+            bits -= 8 & complete.wrapping_neg();
+            buffer &= (1 << bits) - 1; // Only keep the bits that haven't been used
+        }
+        if invalid != 0 {
+            // The value isn't secret if it's invalid, find the character that's invalid
+            return decode_bytes(self.conf, &self.value);
+        }
+        v.truncate(len);
+        Ok(v)
+    }
+
+    /// Decodes the Base64 value to bytes only if it's the canonical encoding of those bytes as
+    /// defined by RFC 4648 section 3.5. Padding must only be at the end and be the exact amount
+    /// needed, and the unused bits of the last digit must be 0. Line endings and spaces are skipped
+    /// if the config ignores whitespace. If the config
+    /// [requires padding on decode](config/struct.Config.html#method.set_require_padding_on_decode)
+    /// the number of digits and padding must also be divisible by 4, even without padding
+    ///
+    /// # Return:
+    /// Result of either the decoded bytes,
+    /// [Base64Error::NonCanonicalEncoding](error/enum.Base64Error.html#variant.NonCanonicalEncoding)
+    /// if the encoding isn't canonical, or
+    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::STANDARD; // Include STANDARD config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_from_string("SA==", STANDARD).unwrap();
+    ///     println!("{:?}", b64.decode_to_bytes_strict()); // Prints Ok([72])
+    ///     let b64 = Base64::new_from_string("SG==", STANDARD).unwrap();
+    ///     println!("{:?}", b64.decode_to_bytes_strict()); // Prints Err(NonCanonicalEncoding)
+    /// }
+    /// ```
+    pub fn decode_to_bytes_strict(&self) -> Result<Vec<u8>, Base64Error> {
+        let pad = self.conf.get_padding();
+        let mut digits: usize = 0;
+        let mut pads: usize = 0;
+        let mut last_digit: u128 = 0;
+        for (index, c) in self.value.chars().enumerate() {
+            if self.conf.is_ignored_whitespace(c) {
+                // Skip line endings and spaces
+            } else if pad == Some(c) {
+                pads += 1;
+            } else if pads > 0 {
+                // Padding is only allowed at the end
+                return Err(Base64Error::NonCanonicalEncoding);
+            } else {
+                match self.conf.digit_of(c) {
+                    Some(digit) => last_digit = digit,
+                    None => {
+                        return Err(Base64Error::InvalidBase64CharacterError {
+                            character: c,
+                            index,
+                        })
+                    }
+                }
+                digits += 1;
+            }
+        }
+        // A single digit in the last group can't hold a byte
+        let unused_bits = match digits % 4 {
+            0 => 0,
+            2 => 4,
+            3 => 2,
+            _ => return Err(Base64Error::NonCanonicalEncoding),
+        };
+        let expected_pads = if pad.is_some() {
+            (4 - digits % 4) % 4
+        } else {
+            0
+        };
+        if pads != expected_pads || last_digit & ((1 << unused_bits) - 1) != 0 {
+            return Err(Base64Error::NonCanonicalEncoding);
+        }
+        if self.conf.get_require_padding_on_decode() && !(digits + pads).is_multiple_of(4) {
+            // The encoder left out padding the decoder requires
+            return Err(Base64Error::NonCanonicalEncoding);
+        }
+        decode_bytes(self.conf, &self.value)
+    }
+
+    /// Decodes the Base64 value to bytes and formats each byte as 2 lower case hexadecimal digits
+    ///
+    /// # Return:
+    /// the hexadecimal String of the decoded bytes
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::MIME; // Include MIME config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hello".as_bytes(), MIME);
+    ///     println!("{}", b64.to_hex_string()); // Prints 48656c6c6f
+    /// }
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let mut hex: String = String::new();
+        for b in self.decode_to_bytes() {
+            hex.push(HEX[usize::from(b >> 4)] as char);
+            hex.push(HEX[usize::from(b & 0xF)] as char);
+        }
+        hex
+    }
+
+    /// Decodes the Base64 value to bytes and formats each byte as 8 binary digits, most
+    /// significant bit first
+    ///
+    /// # Return:
+    /// the binary String of the decoded bytes
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::MIME; // Include MIME config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME);
+    ///     println!("{}", b64.to_binary_string()); // Prints 0100100001101001
+    /// }
+    /// ```
+    pub fn to_binary_string(&self) -> String {
+        let mut binary: String = String::new();
+        for b in self.decode_to_bytes() {
+            binary.push_str(&convert_u8_to_binary_string(b));
+        }
+        binary
     }
 
     /// Loop over Base64 number convert each value to it's corresponding unsigned value and sum all
     /// of those
     ///
     /// # Return:
-    /// Result with either the u128 or
-    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError)
+    /// Result with either the u128,
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError), or
+    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    /// if there's whitespace the config doesn't ignore
     ///
     /// # Example:
     /// ```
@@ -46,128 +396,391 @@ impl<'a> Base64<'a> {
     /// }
     /// ```
     pub fn decode_to_unsigned(&self) -> Result<u128, Base64Error> {
-        let mut dec: u128 = 0;
-        // Strip padding from self.value
-        let stripped_vec = remove_padding(self.conf.get_padding(), &self.value);
-        for (i, ch) in stripped_vec.iter().enumerate() {
-            match convert_char_to_decimal(&self.conf, *ch, (stripped_vec.len() - (i + 1)) as u32) {
-                Some(val) => match dec.checked_add(val) {
-                    // Check possible addition overflow
-                    Some(val) => {
-                        dec = val;
-                    }
-                    None => {
-                        return Err(Base64Error::OverflowError);
-                    }
-                },
-                None => {
-                    return Err(Base64Error::OverflowError);
-                }
+        decode_unsigned(self.conf, &self.value)
+    }
+
+    /// Decode the Base64 number to a big-endian unsigned integer of any size, the reverse of
+    /// [new_encode_bignum](struct.Base64.html#method.new_encode_bignum)
+    ///
+    /// # Return:
+    /// The big-endian bytes of the number without leading 0 bytes, a single 0 byte if the number is
+    /// 0
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_unsigned(&65538, STANDARD);
+    ///     println!("{:?}", b64.decode_to_bignum()); // Prints [1, 0, 2]
+    /// }
+    /// ```
+    pub fn decode_to_bignum(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = Vec::new();
+        for digit in self.digits() {
+            // Multiply by 64 then add the digit
+            let mut carry = digit as u32;
+            for b in bytes.iter_mut().rev() {
+                let current = u32::from(*b) * 64 + carry;
+                *b = (current & 0xFF) as u8;
+                carry = current >> 8;
             }
+            while carry > 0 {
+                bytes.insert(0, (carry & 0xFF) as u8);
+                carry >>= 8;
+            }
+        }
+        if bytes.is_empty() {
+            bytes.push(0);
+        }
+        bytes
+    }
+
+    /// Decode the Base64 number to a u128 and return the little-endian bytes of the number
+    /// without trailing 0 bytes. This is the numeric value, which is different from
+    /// [decode_to_bytes](struct.Base64.html#method.decode_to_bytes) which decodes data
+    ///
+    /// # Return:
+    /// Result with either the little-endian bytes, a single 0 byte if the number is 0, or
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_unsigned(&65538, STANDARD);
+    ///     println!("{:?}", b64.to_le_bytes()); // Prints Ok([2, 0, 1])
+    /// }
+    /// ```
+    pub fn to_le_bytes(&self) -> Result<Vec<u8>, Base64Error> {
+        let bytes = self.decode_to_unsigned()?.to_le_bytes();
+        // Keep at least 1 byte for 0
+        let len = bytes.iter().rposition(|b| *b != 0).unwrap_or(0) + 1;
+        Ok(bytes[..len].to_vec())
+    }
+
+    /// Decode the Base64 number to a u128 and return the big-endian bytes of the number without
+    /// leading 0 bytes. This is the numeric value, which is different from
+    /// [decode_to_bytes](struct.Base64.html#method.decode_to_bytes) which decodes data
+    ///
+    /// # Return:
+    /// Result with either the big-endian bytes, a single 0 byte if the number is 0, or
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_unsigned(&65538, STANDARD);
+    ///     println!("{:?}", b64.to_be_bytes()); // Prints Ok([1, 0, 2])
+    /// }
+    /// ```
+    pub fn to_be_bytes(&self) -> Result<Vec<u8>, Base64Error> {
+        let bytes = self.decode_to_unsigned()?.to_be_bytes();
+        // Keep at least 1 byte for 0
+        let start = bytes
+            .iter()
+            .position(|b| *b != 0)
+            .unwrap_or(bytes.len() - 1);
+        Ok(bytes[start..].to_vec())
+    }
+
+    /// Decode the Base64 number to a signed value, reversing the zig-zag encoding used by
+    /// [new_encode_signed](struct.Base64.html#method.new_encode_signed)
+    ///
+    /// # Return:
+    /// Result with either the i128 or
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_signed(&-8, STANDARD);
+    ///     match b64.decode_to_signed() {
+    ///         Ok(value) => println!("{}", value), // Prints -8
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_to_signed(&self) -> Result<i128, Base64Error> {
+        let unsigned = self.decode_to_unsigned()?;
+        Ok(((unsigned >> 1) as i128) ^ -((unsigned & 1) as i128))
+    }
+
+    /// Decode the Base64 number to a u64 without truncating
+    ///
+    /// # Return:
+    /// Result with either the u64 or
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError) if the
+    /// value doesn't fit in a u64
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_unsigned(&10000, STANDARD);
+    ///     match b64.decode_to_u64() {
+    ///         Ok(value) => println!("{}", value), // Prints 10000
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_to_u64(&self) -> Result<u64, Base64Error> {
+        self.decode_to_narrow_unsigned()
+    }
+
+    /// Decode the Base64 number to a u32 without truncating
+    ///
+    /// # Return:
+    /// Result with either the u32 or
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError) if the
+    /// value doesn't fit in a u32
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_unsigned(&10000, STANDARD);
+    ///     match b64.decode_to_u32() {
+    ///         Ok(value) => println!("{}", value), // Prints 10000
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_to_u32(&self) -> Result<u32, Base64Error> {
+        self.decode_to_narrow_unsigned()
+    }
+
+    /// Decode the Base64 number to a u16 without truncating
+    ///
+    /// # Return:
+    /// Result with either the u16 or
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError) if the
+    /// value doesn't fit in a u16
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_unsigned(&10000, STANDARD);
+    ///     match b64.decode_to_u16() {
+    ///         Ok(value) => println!("{}", value), // Prints 10000
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_to_u16(&self) -> Result<u16, Base64Error> {
+        self.decode_to_narrow_unsigned()
+    }
+
+    /// Decode the Base64 number to a u8 without truncating
+    ///
+    /// # Return:
+    /// Result with either the u8 or
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError) if the
+    /// value doesn't fit in a u8
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// fn main() {
+    ///     let b64 =  Base64::new_encode_unsigned(&200, STANDARD);
+    ///     match b64.decode_to_u8() {
+    ///         Ok(value) => println!("{}", value), // Prints 200
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_to_u8(&self) -> Result<u8, Base64Error> {
+        self.decode_to_narrow_unsigned()
+    }
+
+    /// Decode to u128 then convert to a narrower unsigned type, failing with an OverflowError
+    /// instead of truncating
+    fn decode_to_narrow_unsigned<T: TryFrom<u128>>(&self) -> Result<T, Base64Error> {
+        match T::try_from(self.decode_to_unsigned()?) {
+            Ok(value) => Ok(value),
+            Err(_) => Err(Base64Error::OverflowError),
         }
-        Ok(dec)
     }
 }
 
-/// Decodes a &str to a Base64 String
-fn decode_bytes<'a>(conf: &'a Config, s: &str) -> Vec<u8> {
-    //let mut binary: String = String::new();
-    let mut binary: Vec<char> = Vec::new();
-    for i in s.chars() {
-        if conf.get_padding().is_some() && i == conf.get_padding().unwrap() {
-            // Skip padding characters
-        } else if i != ' ' && i != '\n' {
-            // Skip newlines and spaces
-            binary.append(
-                convert_decimal_to_binary(base64_char_to_decimal(conf.get_character_set(), i))
-                    .as_mut(),
-            );
+/// Decodes a &str to a Base64 String, accumulating 6 bits per character and emitting a byte
+/// whenever 8 bits are available. Any bits left over at the end are the 0s added when encoding
+/// so they are dropped
+pub(crate) fn decode_bytes(conf: &Config, s: &str) -> Result<Vec<u8>, Base64Error> {
+    #[cfg(feature = "simd")]
+    {
+        if let Some(decoded) = super::simd::decode(conf, s) {
+            return decoded;
         }
     }
-    // Add additional 0s to make sure it's divisible by 8
-    while binary.len() % 8 != 0 {
-        binary.push('0');
+    let mut v: Vec<u8> = Vec::with_capacity(s.len() / 4 * 3 + 3);
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+    for (index, i) in s.chars().enumerate() {
+        decode_char(conf, i, index, &mut buffer, &mut bits, &mut v)?;
     }
-    let mut v: Vec<u8> = Vec::new();
-    for i in (0..binary.len()).step_by(8) {
-        if !is_8bit_all_0s(&binary[i..i + 8]) {
-            //Skip padding
-            v.push(convert_8bit_to_u8(&binary[i..i + 8]));
+    Ok(v)
+}
+
+/// Decodes the characters to a u128 with Horner's method, padding and whitespace the config
+/// ignores are skipped
+pub(crate) fn decode_unsigned(conf: &Config, s: &str) -> Result<u128, Base64Error> {
+    let mut dec: u128 = 0;
+    for (index, ch) in s.chars().enumerate() {
+        if Some(ch) == conf.get_padding() || conf.is_ignored_whitespace(ch) {
+            // Skip padding, line endings, and spaces
+            continue;
+        } else if ch == ' ' || ch == '\n' || ch == '\r' {
+            return Err(Base64Error::InvalidBase64CharacterError {
+                character: ch,
+                index,
+            });
+        }
+        // Shift the previous digits up a place and add the new digit, checking for overflow
+        match dec
+            .checked_mul(64)
+            .and_then(|val| val.checked_add(convert_char_to_decimal(conf, ch)))
+        {
+            Some(val) => dec = val,
+            None => return Err(Base64Error::OverflowError),
         }
     }
-    v
+    Ok(dec)
 }
 
-/// Converts a character in Base64 to it's decimal equivalent which is val * 64^place
-/// Param: val, the character value
-/// Param: place, the place
-/// Return: Either None if any value isn't in the proper bounds or u128
-fn convert_char_to_decimal(conf: &Config, val: char, place: u32) -> Option<u128> {
-    match 64u128.checked_pow(place) {
-        // Check pow overflow
-        Some(value) => {
-            match (base64_char_to_decimal(conf.get_character_set(), val)).checked_mul(value) {
-                Some(val) => Some(val),
-                None => None,
+/// Decodes a single character, its 6 bits are added to buffer and every whole byte is pushed
+/// onto v. bits is the number of bits in buffer that haven't been used, padding and whitespace
+/// the config ignores are skipped
+pub(crate) fn decode_char(
+    conf: &Config,
+    c: char,
+    index: usize,
+    buffer: &mut u32,
+    bits: &mut u32,
+    v: &mut Vec<u8>,
+) -> Result<(), Base64Error> {
+    if conf.get_padding().is_some() && c == conf.get_padding().unwrap() {
+        // Skip padding characters
+    } else if !conf.is_ignored_whitespace(c) {
+        // Skip line endings and spaces
+        let digit = match conf.digit_of(c) {
+            Some(digit) => digit,
+            None => {
+                return Err(Base64Error::InvalidBase64CharacterError {
+                    character: c,
+                    index,
+                })
             }
+        };
+        *buffer = (*buffer << 6) | digit as u32;
+        *bits += 6;
+        if *bits >= 8 {
+            *bits -= 8;
+            v.push((*buffer >> *bits) as u8);
+            *buffer &= (1 << *bits) - 1; // Only keep the bits that haven't been used
         }
-        None => None,
     }
+    Ok(())
+}
+
+/// 1 if a and b are equal, 0 otherwise, without branching
+fn ct_eq(a: u32, b: u32) -> u32 {
+    let x = a ^ b;
+    // The top bit of x | -x is only clear when x is 0
+    ((x | x.wrapping_neg()) >> 31) ^ 1
 }
 
-/// Converts a decimal to binary by getting value % 2 then dividing by 2 until the value is 0
-/// Prepend 0s until the binary is of length 6. This is in the reverse order so reverse it.
-fn convert_decimal_to_binary(value: u128) -> Vec<char> {
-    let mut v = value;
-    let mut vec: Vec<char> = Vec::new();
-    while v != 0 {
-        match v % 2 {
-            0 => vec.push('0'),
-            1 => vec.push('1'),
-            _ => vec.push('0'), // Impossible case
-        }
-        v /= 2;
-    }
-    // Prepend 0s so that it's of length 6
-    while vec.len() < 6 {
-        vec.push('0');
-    }
-    vec.reverse(); // Flip vector to proper order
-    vec
+/// Iterator that decodes characters as they're consumed with
+/// [decode_char](fn.decode_char.html), the bits that don't make up a whole byte yet are kept in
+/// buffer
+pub(crate) struct DecodeIter<'b, I> {
+    conf: &'b Config<'b>,
+    chars: core::iter::Enumerate<I>,
+    buffer: u32,
+    bits: u32,
+    // Bytes decode_char pushed that haven't been yielded yet, at most 1 per character
+    decoded: Vec<u8>,
+    // Set after an invalid character so nothing else is yielded
+    done: bool,
 }
 
-/// Converts a 6 bit binary value to a u128
-fn convert_8bit_to_u8(s: &[char]) -> u8 {
-    let mut value: u8 = 0;
-    for (i, c) in s.iter().enumerate() {
-        // if it's 1 add 2^place
-        if *c == '1' {
-            value += 2u8.pow(((s.len() - 1) - i) as u32);
+impl<'b, I: Iterator<Item = char>> DecodeIter<'b, I> {
+    pub(crate) fn new(chars: I, conf: &'b Config<'b>) -> Self {
+        DecodeIter {
+            conf,
+            chars: chars.enumerate(),
+            buffer: 0,
+            bits: 0,
+            decoded: Vec::with_capacity(1),
+            done: false,
         }
     }
-    value
 }
 
-/// Checks if all 8 bits (represented as a slice of chars) are all 0s
-fn is_8bit_all_0s(s: &[char]) -> bool {
-    for c in s {
-        if *c != '0' {
-            return false;
+impl<'b, I: Iterator<Item = char>> Iterator for DecodeIter<'b, I> {
+    type Item = Result<u8, Base64Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        for (index, c) in &mut self.chars {
+            let decoded = decode_char(
+                self.conf,
+                c,
+                index,
+                &mut self.buffer,
+                &mut self.bits,
+                &mut self.decoded,
+            );
+            if let Err(e) = decoded {
+                self.done = true;
+                return Some(Err(e));
+            }
+            if let Some(byte) = self.decoded.pop() {
+                return Some(Ok(byte));
+            }
         }
+        None
     }
-    true
 }
 
-fn remove_padding(pad: Option<char>, v: &[char]) -> Vec<char> {
+/// Converts a character in Base64 to it's decimal equivalent, 0 if it isn't in the character set
+/// Param: val, the character value
+fn convert_char_to_decimal(conf: &Config, val: char) -> u128 {
+    conf.digit_of(val).unwrap_or(0)
+}
+
+/// Removes every padding character from the &str
+#[allow(clippy::unnecessary_unwrap)]
+pub(crate) fn remove_padding(pad: Option<char>, v: &str) -> String {
     if pad.is_some() {
-        let mut new_v: Vec<char> = Vec::new();
-        for i in v {
-            if *i != pad.unwrap() {
-                new_v.push(*i);
+        let mut new_v: String = String::with_capacity(v.len());
+        for i in v.chars() {
+            if i != pad.unwrap() {
+                new_v.push(i);
             }
         }
         new_v
     } else {
-        v.to_vec()
+        v.to_string()
     }
 }
@@ -1,7 +1,14 @@
-use super::{config::Config, decimal_to_base64, decimal_to_base64_char, Base64};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::config::{Config, LineEnding};
+use super::error::Base64Error;
+use super::{decimal_to_base64, decimal_to_base64_char, Base64, ByteOrder};
 
 impl<'a> Base64<'a> {
-    /// Creates a base64 number equivalent to the provided unsigned value
+    /// Creates a base64 number equivalent to the provided unsigned value, numbers are never padded
+    /// because padding only applies to bytes encoded with
+    /// [new_encode_bytes](struct.Base64.html#method.new_encode_bytes)
     ///
     /// # Parameters:
     /// unsigned, the unsigned value to convert
@@ -18,29 +25,63 @@ impl<'a> Base64<'a> {
     /// fn main() {
     ///     let b64 = Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING); // Sets b64 to the equivalent Base64 of 128
     ///     println!("{}", b64); // prints "CA"
-    ///     let b64 = Base64::new_encode_unsigned(&128, URL_SAFE_PADDING); // Sets b64 to the equivalent Base64 of 128 with padding
-    ///     println!("{}", b64); // prints "CA==" so it's divisble by 4
+    ///     let b64 = Base64::new_encode_unsigned(&128, URL_SAFE_PADDING); // Sets b64 to the equivalent Base64 of 128
+    ///     println!("{}", b64); // prints "CA" as well, numbers aren't padded
     /// }
     /// ```
     pub fn new_encode_unsigned(unsigned: &u128, conf: &'a Config<'a>) -> Self {
-        let mut b64 = {
-            if *unsigned > 0 {
-                Base64 {
-                    value: decimal_to_base64(&conf, *unsigned),
-                    conf,
-                }
-            } else {
-                Base64 {
-                    value: vec!['A'],
-                    conf,
-                }
+        Base64 {
+            value: decimal_to_base64(conf, *unsigned),
+            conf,
+            bytes: false,
+        }
+    }
+
+    /// Creates a base64 number equivalent to the provided unsigned value with at least width
+    /// digits, leading 0 digits (the first character of the character set) are added until it's
+    /// wide enough. Useful for fixed width IDs that sort in order
+    ///
+    /// # Parameters:
+    /// unsigned, the unsigned value to convert
+    ///
+    /// width, the minimum number of digits
+    ///
+    /// # Return:
+    /// the new base64 number equivalent to the unsigned value passed
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_unsigned_min_width(&10, 4, URL_SAFE_NO_PADDING);
+    ///     println!("{}", b64); // prints "AAAK"
+    /// }
+    /// ```
+    pub fn new_encode_unsigned_min_width(
+        unsigned: &u128,
+        width: usize,
+        conf: &'a Config<'a>,
+    ) -> Self {
+        let digits = decimal_to_base64(conf, *unsigned);
+        let len = digits.chars().count();
+        let mut value: String = String::with_capacity(digits.len() + width.saturating_sub(len));
+        if let Some(zero) = decimal_to_base64_char(conf.get_character_set(), 0) {
+            for _i in len..width {
+                value.push(zero);
             }
-        };
-        b64.add_padding(); // pad if necessary
-        b64
+        }
+        value.push_str(&digits);
+        Base64 {
+            value,
+            conf,
+            bytes: false,
+        }
     }
 
-    /// Sets the base64 value from an unsigned integer u128
+    /// Sets the base64 value from an unsigned integer u128, numbers are never padded
     ///
     /// # Parameters:
     /// the unsigned value to set the b64 equivalent to
@@ -55,16 +96,97 @@ impl<'a> Base64<'a> {
     ///     let mut b64 = Base64::default(); // Sets b64 to default which is Standard config and "A"
     ///     println!("{}", b64); // prints "A"
     ///     b64.encode_unsigned(&2);
-    ///     println!("{}", b64); // prints "C===" because Standard has padding
+    ///     println!("{}", b64); // prints "C" even though Standard has padding
     /// }
     /// ```
     pub fn encode_unsigned(&mut self, unsigned: &u128) {
-        if *unsigned > 0 {
-            self.value = decimal_to_base64(&self.conf, *unsigned);
-        } else {
-            self.value = vec!['A'];
+        self.value = decimal_to_base64(self.conf, *unsigned);
+        self.bytes = false;
+    }
+
+    /// Creates a base64 number equivalent to the provided signed value using
+    /// [zig-zag encoding](https://developers.google.com/protocol-buffers/docs/encoding#signed-integers)
+    /// so values with a small magnitude stay short, 0 is "A", -1 is "B", 1 is "C", -2 is "D" and so
+    /// on
+    ///
+    /// # Parameters:
+    /// signed, the signed value to convert
+    ///
+    /// # Return:
+    /// the new base64 number equivalent to the zig-zag encoded signed value passed
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_signed(&-1, URL_SAFE_NO_PADDING); // Sets b64 to the equivalent Base64 of -1
+    ///     println!("{}", b64); // prints "B"
+    /// }
+    /// ```
+    pub fn new_encode_signed(signed: &i128, conf: &'a Config<'a>) -> Self {
+        Base64::new_encode_unsigned(&zig_zag_encode(*signed), conf)
+    }
+
+    /// Creates a base64 number equivalent to the provided bytes interpreted as a big-endian
+    /// unsigned integer of any size, unlike
+    /// [new_encode_unsigned](struct.Base64.html#method.new_encode_unsigned) this isn't limited to
+    /// a u128. This is different from [new_encode_bytes](struct.Base64.html#method.new_encode_bytes)
+    /// which encodes the data rather than the number
+    ///
+    /// # Parameters:
+    /// bytes_be, the big-endian bytes of the number, leading 0 bytes are ignored
+    ///
+    /// # Return:
+    /// the new base64 number equivalent to the number passed, "A" if all bytes are 0
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bignum(&[1, 0, 2], URL_SAFE_NO_PADDING); // Sets b64 to the equivalent Base64 of 65538
+    ///     println!("{}", b64); // prints "QAC"
+    /// }
+    /// ```
+    pub fn new_encode_bignum(bytes_be: &[u8], conf: &'a Config<'a>) -> Self {
+        Base64 {
+            value: bignum_to_base64(conf, bytes_be),
+            conf,
+            bytes: false,
         }
-        self.add_padding();
+    }
+
+    /// Creates a base64 number equivalent to the provided bytes interpreted as a little-endian
+    /// unsigned integer of any size, like
+    /// [new_encode_bignum](struct.Base64.html#method.new_encode_bignum) with the bytes reversed.
+    /// This is the numeric value, which is different from
+    /// [new_encode_bytes](struct.Base64.html#method.new_encode_bytes) which encodes data
+    ///
+    /// # Parameters:
+    /// bytes_le, the little-endian bytes of the number, trailing 0 bytes are ignored
+    ///
+    /// # Return:
+    /// the new base64 number equivalent to the number passed, "A" if all bytes are 0
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_le_bytes(&[2, 0, 1], URL_SAFE_NO_PADDING); // Sets b64 to the equivalent Base64 of 65538
+    ///     println!("{}", b64); // prints "QAC"
+    /// }
+    /// ```
+    pub fn new_encode_le_bytes(bytes_le: &[u8], conf: &'a Config<'a>) -> Self {
+        let bytes_be: Vec<u8> = bytes_le.iter().rev().cloned().collect();
+        Base64::new_encode_bignum(&bytes_be, conf)
     }
 
     /// Encodes the provided bytes slice into Base64
@@ -91,9 +213,185 @@ impl<'a> Base64<'a> {
     /// ```
     pub fn new_encode_bytes(s: &[u8], conf: &'a Config) -> Self {
         Base64 {
-            value: encode_bytes(conf, s).chars().collect(),
+            value: encode_bytes(conf, s),
             conf,
+            bytes: true,
+        }
+    }
+
+    /// Encodes the provided bytes slice into Base64 like
+    /// [new_encode_bytes](struct.Base64.html#method.new_encode_bytes) unless the result would be
+    /// longer than max_output_len characters, the length is computed with
+    /// [checked_encoded_len](fn.checked_encoded_len.html) before anything is allocated. Useful to
+    /// limit the memory untrusted input can use
+    ///
+    /// # Parameters:
+    /// &[u8] the bytes to convert
+    ///
+    /// The configuration struct
+    ///
+    /// max_output_len, the maximum number of characters including padding and line endings
+    ///
+    /// # Returns:
+    /// The new Base64 number or a
+    /// [Base64Error::OutputTooLarge](error/enum.Base64Error.html#variant.OutputTooLarge) if it
+    /// would be longer than max_output_len or its length doesn't fit in a usize
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::MIME; // Include MIME config
+    ///
+    /// fn main() {
+    ///     match Base64::try_new_encode_bytes("Hello".as_bytes(), MIME, 8) {
+    ///         Ok(value) => println!("{}", value), // Prints SGVsbG8=
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn try_new_encode_bytes(
+        s: &[u8],
+        conf: &'a Config,
+        max_output_len: usize,
+    ) -> Result<Self, Base64Error> {
+        match super::checked_encoded_len(s.len(), conf) {
+            Some(len) if len <= max_output_len => {}
+            _ => return Err(Base64Error::OutputTooLarge),
         }
+        Ok(Base64::new_encode_bytes(s, conf))
+    }
+
+    /// Encodes the provided bytes slice into Base64 in the provided byte order, with
+    /// [LittleEndian](enum.ByteOrder.html#variant.LittleEndian) the bytes are reversed before
+    /// they're grouped into characters. Decode with
+    /// [decode_to_bytes_with_order](struct.Base64.html#method.decode_to_bytes_with_order) and the
+    /// same order to get the bytes back
+    ///
+    /// # Parameters:
+    /// &[u8] the bytes to convert
+    ///
+    /// The configuration struct
+    ///
+    /// The order of the bytes
+    ///
+    /// # Returns:
+    /// The new Base64 number
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64, ByteOrder};
+    /// use lb64::config::MIME; // Include MIME config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes_with_order(&[1, 2, 3], MIME, ByteOrder::LittleEndian);
+    ///     println!("{}", b64); // Prints AwIB, the encoding of [3, 2, 1]
+    /// }
+    /// ```
+    pub fn new_encode_bytes_with_order(s: &[u8], conf: &'a Config, order: ByteOrder) -> Self {
+        match order {
+            ByteOrder::BigEndian => Base64::new_encode_bytes(s, conf),
+            ByteOrder::LittleEndian => {
+                let reversed: Vec<u8> = s.iter().rev().cloned().collect();
+                Base64::new_encode_bytes(&reversed, conf)
+            }
+        }
+    }
+
+    /// Encodes the provided bytes slice into Base64 like
+    /// [new_encode_bytes](struct.Base64.html#method.new_encode_bytes) using multiple threads,
+    /// the result is exactly the same. Requires the `rayon` feature
+    ///
+    /// The bytes are split into chunks of a multiple of 3 bytes so every chunk encodes to whole
+    /// groups of 4 characters and only the last chunk can have padding. Chunks are encoded without
+    /// line endings, because a chunk can start in the middle of a line, and the lines are wrapped
+    /// once all chunks are joined
+    ///
+    /// # Parameters:
+    /// &[u8] the bytes to convert
+    ///
+    /// The configuration struct
+    ///
+    /// # Returns:
+    /// The new Base64 number
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::MIME; // Include MIME config
+    ///
+    /// fn main() {
+    ///     let bytes = vec![0; 1 << 20];
+    ///     let b64 = Base64::new_encode_bytes_parallel(&bytes, MIME);
+    ///     println!("{}", b64 == Base64::new_encode_bytes(&bytes, MIME)); // Prints true
+    /// }
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn new_encode_bytes_parallel(s: &[u8], conf: &'a Config<'a>) -> Self {
+        use rayon::prelude::*;
+
+        let chunks: Vec<String> = s
+            .par_chunks(PARALLEL_CHUNK_LEN)
+            .map(|chunk| {
+                let mut out: String = String::with_capacity(chunk.len().div_ceil(3) * 4);
+                let mut count = 0;
+                for group in chunk.chunks(3) {
+                    encode_group_at(conf, None, group, &mut out, &mut count);
+                }
+                out
+            })
+            .collect();
+        let mut b64 = Base64 {
+            value: chunks.concat(),
+            conf,
+            bytes: true,
+        };
+        if conf.get_line_length().is_some() {
+            b64.rewrap();
+        }
+        b64
+    }
+
+    /// Creates a Base64 number by encoding the bytes of a hexadecimal &str, upper and lower case
+    /// hexadecimal digits are accepted
+    ///
+    /// # Parameters:
+    /// hex, the hexadecimal &str with 2 digits per byte
+    ///
+    /// conf, the configuration struct
+    ///
+    /// # Returns:
+    /// The new Base64 number or a
+    /// [Base64Error::InvalidHexError](error/enum.Base64Error.html#variant.InvalidHexError) if the
+    /// length is odd or a character isn't a hexadecimal digit
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::MIME; // Include MIME config
+    ///
+    /// fn main() {
+    ///     match Base64::from_hex("48656c6c6f", MIME) {
+    ///         Ok(value) => println!("{}", value), // Prints SGVsbG8=
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn from_hex(hex: &str, conf: &'a Config<'a>) -> Result<Self, Base64Error> {
+        if !hex.len().is_multiple_of(2) {
+            return Err(Base64Error::InvalidHexError);
+        }
+        let mut bytes: Vec<u8> = Vec::with_capacity(hex.len() / 2);
+        for pair in hex.as_bytes().chunks(2) {
+            match (hex_digit(pair[0]), hex_digit(pair[1])) {
+                (Some(high), Some(low)) => bytes.push(high << 4 | low),
+                _ => return Err(Base64Error::InvalidHexError),
+            }
+        }
+        Ok(Base64::new_encode_bytes(&bytes, conf))
     }
 
     /// Sets the Base64 value to the encoded byte value in base64
@@ -117,41 +415,246 @@ impl<'a> Base64<'a> {
     /// }
     /// ```
     pub fn encode_bytes(&mut self, s: &[u8]) {
-        self.value = encode_bytes(self.conf, s).chars().collect();
+        self.value = encode_bytes(self.conf, s);
+        self.bytes = true;
+    }
+
+    /// Removes the line endings from the Base64 number and inserts them again according to the
+    /// current line length of the config, if the line length is None all wrapping is removed.
+    /// Only digits count towards the line length, padding doesn't
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::{Config, MIME};
+    ///
+    /// fn main() {
+    ///     let mut conf: Config = MIME.clone();
+    ///     conf.set_line_length(Some(4));
+    ///     let mut b64 = Base64::new_encode_bytes("Hello".as_bytes(), MIME);
+    ///     println!("{}", b64); // Prints "SGVsbG8="
+    ///     b64.set_config(&conf);
+    ///     b64.rewrap();
+    ///     println!("{:?}", b64.to_string()); // Prints "SGVs\r\nbG8="
+    /// }
+    /// ```
+    pub fn rewrap(&mut self) {
+        self.value = self.wrapped(self.conf.get_line_length());
+    }
+
+    /// Creates a String of the Base64 number on a single line, all line endings are removed
+    /// regardless of the line length of the config
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::MIME;
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes(&[0; 60], MIME);
+    ///     println!("{}", b64.to_string_unwrapped()); // Prints 80 As on one line
+    /// }
+    /// ```
+    pub fn to_string_unwrapped(&self) -> String {
+        self.value
+            .chars()
+            .filter(|c| *c != '\n' && *c != '\r')
+            .collect()
+    }
+
+    /// Creates a String of the Base64 number wrapped at width digits regardless of the line
+    /// length of the config, lines end with the line ending of the config. Only digits count
+    /// towards the width, padding doesn't
+    ///
+    /// # Parameters:
+    /// width, the maximum number of digits on a line, 0 doesn't wrap
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64;
+    /// use lb64::{Base64};
+    /// use lb64::config::STANDARD;
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hello".as_bytes(), STANDARD);
+    ///     println!("{:?}", b64.to_string_wrapped(4)); // Prints "SGVs\nbG8="
+    /// }
+    /// ```
+    pub fn to_string_wrapped(&self, width: usize) -> String {
+        self.wrapped(Some(width))
+    }
+
+    /// The value with the old line endings removed and wrapped at len digits
+    fn wrapped(&self, len: Option<usize>) -> String {
+        let mut wrapped: String = String::with_capacity(self.value.len());
+        let mut count = 0;
+        for c in self.value.chars() {
+            if c == '\n' || c == '\r' {
+                // Skip old line endings
+                continue;
+            } else if Some(c) == self.conf.get_padding() || c == ' ' {
+                // Padding and spaces don't count towards the line length
+                wrapped.push(c);
+            } else {
+                push_wrapped_at(
+                    len,
+                    self.conf.get_line_ending(),
+                    &mut wrapped,
+                    c,
+                    &mut count,
+                );
+            }
+        }
+        wrapped
     }
 }
 
-/// Check to see if every byte in a 6 long &str is '?'
-fn is_padding(s: &str) -> bool {
-    for i in s.as_bytes() {
-        if *i != b'?' {
-            return false;
+/// Number of bytes each thread of
+/// [new_encode_bytes_parallel](struct.Base64.html#method.new_encode_bytes_parallel) encodes at a
+/// time, a multiple of 3 so no chunk but the last one has padding
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_LEN: usize = 3 * 16 * 1024;
+
+/// Encodes a group of up to 3 bytes into 4 Base64 characters pushing them onto out, if there are
+/// less than 3 bytes the missing bits are 0s and the missing characters are padding if the config
+/// has padding. count is the number of characters on the current line for configs with a line
+/// length
+///
+/// There are 2 kinds of padding. Bit padding fills the last character that has data with 0 bits
+/// so it has 6 bits, 1 byte is 8 bits so 2 characters with 4 zero bits, and 2 bytes are 16 bits
+/// so 3 characters with 2 zero bits. Character padding then adds a padding character for each of
+/// the 4 characters without any data, so "f" is "Zg==" and "fo" is "Zm8="
+pub(crate) fn encode_group(conf: &Config, group: &[u8], out: &mut String, count: &mut usize) {
+    encode_group_at(conf, conf.get_line_length(), group, out, count);
+}
+
+/// Encodes a group of up to 3 bytes like [encode_group](fn.encode_group.html) but wraps lines
+/// at len characters instead of the line length of the config
+fn encode_group_at(
+    conf: &Config,
+    len: Option<usize>,
+    group: &[u8],
+    out: &mut String,
+    count: &mut usize,
+) {
+    let mut bits: u32 = 0;
+    for (i, b) in group.iter().take(3).enumerate() {
+        bits |= u32::from(*b) << (16 - 8 * i);
+    }
+    // 1 byte is 2 characters, 2 bytes are 3 characters, and 3 bytes are 4 characters, the bits
+    // after the last byte are already 0 so the last character is bit padded
+    let chars = group.len().min(3) + 1;
+    for i in 0..4 {
+        if i < chars {
+            let value = (bits >> (18 - 6 * i)) & 0x3F;
+            if let Some(c) = decimal_to_base64_char(conf.get_character_set(), u128::from(value)) {
+                push_wrapped_at(len, conf.get_line_ending(), out, c, count);
+            }
+        } else if let Some(pad) = conf.get_padding() {
+            out.push(pad);
         }
     }
-    true
 }
 
-/// Converts a string of chars to a binary String
-fn convert_bytes_to_binary_string(s: &[u8]) -> String {
-    let mut binary: String = String::new();
-    for c in s.iter() {
-        binary.push_str(&convert_u8_to_binary_string(*c));
+/// Iterator that encodes the bytes one group of 3 at a time, the characters of the current group
+/// are buffered until they're yielded
+pub(crate) struct EncodeIter<'b> {
+    conf: &'b Config<'b>,
+    groups: core::slice::Chunks<'b, u8>,
+    // Characters of the current group, including any line ending
+    buffer: String,
+    // Byte index of the next character in buffer
+    pos: usize,
+    // Characters on the current line
+    count: usize,
+}
+
+impl<'b> EncodeIter<'b> {
+    pub(crate) fn new(bytes: &'b [u8], conf: &'b Config<'b>) -> Self {
+        EncodeIter {
+            conf,
+            groups: bytes.chunks(3),
+            buffer: String::new(),
+            pos: 0,
+            count: 0,
+        }
     }
-    while binary.len() % 6 != 0 {
-        // Make sure it's divisible by 6 for each base64 character
-        binary.push('0');
+}
+
+impl<'b> Iterator for EncodeIter<'b> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.pos >= self.buffer.len() {
+            let group = self.groups.next()?;
+            self.buffer.clear();
+            self.pos = 0;
+            encode_group(self.conf, group, &mut self.buffer, &mut self.count);
+        }
+        let c = self.buffer[self.pos..].chars().next()?;
+        self.pos += c.len_utf8();
+        Some(c)
     }
-    while binary.len() % 24 != 0 {
-        // Add padding if it isn't divisible by 24
-        binary.push('?');
+}
+
+/// Pushes the character onto out, adding the line ending first if len characters are already on
+/// the current line
+pub(crate) fn push_wrapped_at(
+    len: Option<usize>,
+    ending: LineEnding,
+    out: &mut String,
+    c: char,
+    count: &mut usize,
+) {
+    match len {
+        Some(len) if len != 0 => {
+            if *count == len {
+                // at line_length value add the line ending
+                *count = 0;
+                out.push_str(ending.as_str());
+            }
+            *count += 1;
+        }
+        _ => {}
     }
-    binary
+    out.push(c);
+}
+
+/// Converts a big-endian number of any size to base64 by repeatedly dividing it by 64, the
+/// remainder of each division is the next least significant base64 value
+fn bignum_to_base64(conf: &Config, bytes_be: &[u8]) -> String {
+    // Ignore leading 0s
+    let mut num: Vec<u8> = bytes_be.iter().skip_while(|b| **b == 0).cloned().collect();
+    let mut v: Vec<char> = Vec::new();
+    while !num.is_empty() {
+        let mut remainder: u32 = 0;
+        let mut quotient: Vec<u8> = Vec::with_capacity(num.len());
+        for b in &num {
+            // Long division one byte at a time
+            let current = (remainder << 8) | u32::from(*b);
+            remainder = current % 64;
+            if !quotient.is_empty() || current / 64 != 0 {
+                quotient.push((current / 64) as u8);
+            }
+        }
+        v.extend(decimal_to_base64_char(
+            conf.get_character_set(),
+            u128::from(remainder),
+        ));
+        num = quotient;
+    }
+    if v.is_empty() {
+        v.extend(decimal_to_base64_char(conf.get_character_set(), 0));
+    }
+    v.iter().rev().collect() // Reverse to get into proper order
 }
 
-/// Convert a u8 to a String of binary corresponding to it's value
-fn convert_u8_to_binary_string(value: u8) -> String {
+/// Convert a u8 to a String of binary corresponding to it's value, most significant bit first
+pub(crate) fn convert_u8_to_binary_string(value: u8) -> String {
     const U8_LENGTH: usize = 8;
-    let mut binary: String = String::new();
+    let mut binary: String = String::with_capacity(U8_LENGTH);
     for i in (0..U8_LENGTH).rev() {
         // Get each bit in the 8 bit binary and convert it to a char
         binary.push((b'0' + ((value >> i) & 1)) as char);
@@ -159,49 +662,33 @@ fn convert_u8_to_binary_string(value: u8) -> String {
     binary
 }
 
-/// Converts a 6 bit binary value to a u128
-fn convert_6bit_to_u128(s: &str) -> u128 {
-    let mut value: u128 = 0;
-    for (i, c) in s.chars().enumerate() {
-        // if it's 1 add 2^place
-        if c == '1' {
-            value += 2u128.pow(((s.len() - 1) - i) as u32);
-        }
+/// Converts an ASCII hexadecimal digit to it's value
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
     }
-    value
 }
 
-fn encode_bytes<'a>(conf: &'a Config, s: &[u8]) -> String {
-    let binary: String = convert_bytes_to_binary_string(s); // Convert all characters to binary
-    let mut b64_str: String = String::new();
+/// Maps a signed value to an unsigned value so that values with a small magnitude are small
+fn zig_zag_encode(value: i128) -> u128 {
+    ((value << 1) ^ (value >> 127)) as u128
+}
+
+/// Encodes the bytes 3 at a time into 4 Base64 characters each
+fn encode_bytes(conf: &Config, s: &[u8]) -> String {
+    #[cfg(feature = "simd")]
+    {
+        if let Some(encoded) = super::simd::encode(conf, s) {
+            return encoded;
+        }
+    }
+    let mut b64_str: String = String::with_capacity(s.len().div_ceil(3) * 4);
     let mut count = 0;
-    for i in (0..binary.len()).step_by(6) {
-        // Loop over binary getting every 6 bits and converting them to a Base64 value
-        if conf.get_padding().is_some() && is_padding(&binary[i..i + 6]) {
-            // If the config enables padding then
-            // Check to see if the values are padding
-            match conf.get_padding() {
-                Some(c) => b64_str.push(c),
-                None => {
-                    continue;
-                }
-            }
-            continue;
-        }
-        // Convert every 6 bits to 1 Base64 value
-        let value: u128 = convert_6bit_to_u128(&binary[i..i + 6]);
-        let b64_char: char = decimal_to_base64_char(conf.get_character_set(), value);
-        if conf.get_line_length().unwrap_or(0) != 0 && count < conf.get_line_length().unwrap() {
-            // if the line_length is fixed keep a count
-            count += 1;
-        } else if conf.get_line_length().unwrap_or(0) != 0
-            && count == conf.get_line_length().unwrap()
-        {
-            // at line_length value add newline
-            count = 0;
-            b64_str.push('\n');
-        }
-        b64_str.push(b64_char);
+    for group in s.chunks(3) {
+        encode_group(conf, group, &mut b64_str, &mut count);
     }
     b64_str
 }
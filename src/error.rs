@@ -1,6 +1,6 @@
-use std::cmp::PartialEq;
-use std::fmt::{Display, Formatter};
-use std::mem;
+use core::cmp::PartialEq;
+use core::fmt::{Display, Formatter};
+use core::mem;
 
 /// Possible Configuration errors when either setting or creating a new configuration that may occur
 #[derive(Debug)]
@@ -56,10 +56,22 @@ pub enum ConfigError {
     /// let pad = &Some('\n'); // Throws Error because '\n' isn't representable
     /// ```
     PaddingUnrepresentableCharacter,
+    /// Character set has the same ASCII letter in both cases so it can't be case insensitive
+    /// # Example:
+    /// ```
+    /// use lb64::config::{Config, STANDARD};
+    ///
+    /// let mut conf: Config = STANDARD.clone();
+    /// match conf.set_case_insensitive(true) {
+    ///     Ok(()) => println!("This is impossible"),
+    ///     Err(e) => println!("{}", e), // Throws Error because 'A' and 'a' are both in the character set
+    /// }
+    /// ```
+    AmbiguousCaseError,
 }
 
 impl Display for ConfigError {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         match self {
             ConfigError::CharacterSetLengthError => {
                 f.write_str("Provided Character set length isn't 64")
@@ -76,27 +88,17 @@ impl Display for ConfigError {
             ConfigError::PaddingUnrepresentableCharacter => {
                 f.write_str("Padding is a character that is unrepresentable")
             }
+            ConfigError::AmbiguousCaseError => {
+                f.write_str("Character set has a letter in both cases so case can't be ignored")
+            }
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for ConfigError {
-    fn description(&self) -> &str {
-        match *self {
-            ConfigError::CharacterSetLengthError => "Provided Character set length isn't 64",
-            ConfigError::NotUniquePaddingError => {
-                "Padding character provided is already used in character set"
-            }
-            ConfigError::DuplicateCharacterError => {
-                "At least one duplicate character found in character set"
-            }
-            ConfigError::CharacterSetUnrepresentableCharacter => {
-                "Character set has at leaset one unrepresentable character"
-            }
-            ConfigError::PaddingUnrepresentableCharacter => {
-                "Padding is a character that is unrepresentable"
-            }
-        }
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
     }
 }
 
@@ -111,8 +113,10 @@ impl PartialEq for ConfigError {
 pub enum Base64Error {
     /// Unsigned Overflow when decoding Base64 number to unsigned
     ///
-    /// Only applies to
-    /// [Base64::decode_to_unsigned](../struct.Base64.html#method.decode_to_unsigned)
+    /// Applies to
+    /// [Base64::decode_to_unsigned](../struct.Base64.html#method.decode_to_unsigned) and the
+    /// fixed width decodes such as
+    /// [Base64::decode_to_u64](../struct.Base64.html#method.decode_to_u64)
     /// # Example:
     /// ```
     /// use lb64::{Base64, config::MIME};
@@ -124,47 +128,214 @@ pub enum Base64Error {
     /// }
     /// ```
     OverflowError,
-    /// Invalid character in Base64 provided &str
+    /// Invalid character in Base64 provided &str, holds the offending character and its index
+    /// in characters (not bytes) within the &str
     ///
-    /// Only applies to [Base64::new_from_string](../struct.Base64.html#method.new_from_string)
+    /// Applies to [Base64::new_from_string](../struct.Base64.html#method.new_from_string) and
+    /// [Base64::try_decode_to_bytes](../struct.Base64.html#method.try_decode_to_bytes)
     /// # Example:
     /// ```
     /// use lb64::{Base64, config::MIME};
     ///
     /// match Base64::new_from_string(&"^_^", MIME) {
     ///     Ok(value) => println!("This is impossible"),
-    ///     Err(e) => println!("{}", e), // Base64Error::InvalidBase64CharacterError occurred
+    ///     Err(e) => println!("{}", e), // Prints "Invalid character '^' at index 0"
+    /// }
+    /// ```
+    InvalidBase64CharacterError {
+        /// The character that isn't valid
+        character: char,
+        /// The index of the character
+        index: usize,
+    },
+    /// Invalid hexadecimal &str, either a character isn't a hexadecimal digit or the length is odd
+    ///
+    /// Applies to [Base64::from_hex](../struct.Base64.html#method.from_hex)
+    /// # Example:
+    /// ```
+    /// use lb64::{Base64, config::MIME};
+    ///
+    /// match Base64::from_hex("486", MIME) {
+    ///     Ok(value) => println!("This is impossible"),
+    ///     Err(e) => println!("{}", e), // Base64Error::InvalidHexError occurred
+    /// }
+    /// ```
+    InvalidHexError,
+    /// Base64 isn't the canonical encoding of its bytes, the padding is misplaced or the wrong
+    /// amount, or the unused bits of the last digit aren't 0
+    ///
+    /// Applies to [Base64::decode_to_bytes_strict](../struct.Base64.html#method.decode_to_bytes_strict)
+    /// # Example:
+    /// ```
+    /// use lb64::{Base64, config::STANDARD};
+    ///
+    /// let b64 = Base64::new_from_string("SG==", STANDARD).unwrap();
+    /// match b64.decode_to_bytes_strict() {
+    ///     Ok(value) => println!("This is impossible"),
+    ///     Err(e) => println!("{}", e), // Base64Error::NonCanonicalEncoding occurred
+    /// }
+    /// ```
+    NonCanonicalEncoding,
+    /// Two Base64 numbers that are combined don't have the same config
+    ///
+    /// Applies to [Base64::concat](../struct.Base64.html#method.concat)
+    /// # Example:
+    /// ```
+    /// use lb64::{Base64, config::{MIME, URL_SAFE_NO_PADDING}};
+    ///
+    /// let lhs = Base64::new_encode_unsigned(&1, MIME);
+    /// let rhs = Base64::new_encode_unsigned(&1, URL_SAFE_NO_PADDING);
+    /// match lhs.concat(&rhs) {
+    ///     Ok(value) => println!("This is impossible"),
+    ///     Err(e) => println!("{}", e), // Base64Error::ConfigMismatchError occurred
+    /// }
+    /// ```
+    ConfigMismatchError,
+    /// Decoded bytes aren't valid UTF-8
+    ///
+    /// Applies to [Base64::decode_to_string](../struct.Base64.html#method.decode_to_string)
+    /// # Example:
+    /// ```
+    /// use lb64::{Base64, config::MIME};
+    ///
+    /// let b64 = Base64::new_encode_bytes(&[0xFF, 0xFE], MIME);
+    /// match b64.decode_to_string() {
+    ///     Ok(value) => println!("This is impossible"),
+    ///     Err(e) => println!("{}", e), // Base64Error::InvalidUtf8 occurred
+    /// }
+    /// ```
+    InvalidUtf8,
+    /// Encoding would create more characters than the maximum allowed
+    ///
+    /// Applies to [Base64::try_new_encode_bytes](../struct.Base64.html#method.try_new_encode_bytes)
+    /// # Example:
+    /// ```
+    /// use lb64::{Base64, config::MIME};
+    ///
+    /// match Base64::try_new_encode_bytes("Hello".as_bytes(), MIME, 4) {
+    ///     Ok(value) => println!("This is impossible"),
+    ///     Err(e) => println!("{}", e), // Base64Error::OutputTooLarge occurred
     /// }
     /// ```
-    InvalidBase64CharacterError,
+    OutputTooLarge,
+    /// The buffer provided is shorter than the output
+    ///
+    /// Applies to [Base64::decode_to_slice](../struct.Base64.html#method.decode_to_slice) and
+    /// [encode_to_slice](../fn.encode_to_slice.html)
+    /// # Example:
+    /// ```
+    /// use lb64::{Base64, config::MIME};
+    ///
+    /// let b64 = Base64::new_encode_bytes("Hello".as_bytes(), MIME);
+    /// let mut out = [0u8; 4];
+    /// match b64.decode_to_slice(&mut out) {
+    ///     Ok(value) => println!("This is impossible"),
+    ///     Err(e) => println!("{}", e), // Base64Error::BufferTooSmall occurred
+    /// }
+    /// ```
+    BufferTooSmall,
 }
 
 impl Display for Base64Error {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         match *self {
             Base64Error::OverflowError => {
                 f.write_str("Unsigned Overflow when decoding Base64 to unsigned")
             }
-            Base64Error::InvalidBase64CharacterError => {
-                f.write_str("Invalid character in provided Base64 &str")
+            Base64Error::InvalidBase64CharacterError { character, index } => {
+                write!(f, "Invalid character '{}' at index {}", character, index)
+            }
+            Base64Error::InvalidHexError => f.write_str("Invalid hexadecimal &str"),
+            Base64Error::NonCanonicalEncoding => f.write_str("Base64 isn't canonically encoded"),
+            Base64Error::ConfigMismatchError => {
+                f.write_str("Base64 numbers have different configs")
             }
+            Base64Error::InvalidUtf8 => f.write_str("Decoded bytes aren't valid UTF-8"),
+            Base64Error::OutputTooLarge => {
+                f.write_str("Encoded Base64 is longer than the maximum length")
+            }
+            Base64Error::BufferTooSmall => f.write_str("Buffer is too small for the output"),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Base64Error {
-    fn description(&self) -> &str {
-        match *self {
-            Base64Error::OverflowError => {
-                "Unsigned Overflow occured when decoding Base64 to unsigned"
-            }
-            Base64Error::InvalidBase64CharacterError => "Invalid character in provided Base64 &str",
-        }
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
     }
 }
 
+/// Compares only the kind of error, the character and index of an
+/// [InvalidBase64CharacterError](enum.Base64Error.html#variant.InvalidBase64CharacterError) are
+/// ignored
 impl PartialEq for Base64Error {
     fn eq(&self, other: &Base64Error) -> bool {
         mem::discriminant(self) == mem::discriminant(other)
     }
 }
+
+/// Either a [ConfigError](enum.ConfigError.html) or a [Base64Error](enum.Base64Error.html), for
+/// code that both creates configs and decodes Base64 to return a single error type with `?`
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+///
+/// use lb64::config::Config;
+/// use lb64::error::Error;
+/// use lb64::Base64;
+///
+/// fn decode(alphabet: &str, s: &str) -> Result<Vec<u8>, Error> {
+///     let owned = Config::from_alphabet_str(alphabet, Some('='), None)?; // ConfigError
+///     let conf = owned.as_config();
+///     let b64 = Base64::new_from_string(s, &conf)?; // Base64Error
+///     Ok(b64.decode_to_bytes())
+/// }
+///
+/// fn main() {
+///     let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+///     match decode(alphabet, "SGk=") {
+///         Ok(bytes) => println!("{:?}", bytes), // Prints [72, 105]
+///         Err(e) => println!("{}", e),
+///     }
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// Error creating or changing a config
+    Config(ConfigError),
+    /// Error decoding a Base64 number
+    Base64(Base64Error),
+}
+
+impl From<ConfigError> for Error {
+    fn from(e: ConfigError) -> Self {
+        Error::Config(e)
+    }
+}
+
+impl From<Base64Error> for Error {
+    fn from(e: Base64Error) -> Self {
+        Error::Base64(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        match self {
+            Error::Config(e) => Display::fmt(e, f),
+            Error::Base64(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Config(e) => Some(e),
+            Error::Base64(e) => Some(e),
+        }
+    }
+}
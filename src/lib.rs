@@ -36,8 +36,6 @@
 //! ```
 //! See Examples for more.
 
-// Allow use of pow to prevent panics from overflowing
-#![feature(no_panic_pow)]
 // Prevent trivial casts, require implement debug, completely safe code, and require documentation
 #![deny(
     trivial_numeric_casts,
@@ -46,19 +44,45 @@
     unsafe_code,
     missing_docs
 )]
-// Requiring a is_empty function doesn't make sense in this context
-#![allow(clippy::len_without_is_empty)]
+// Only requires an allocator, std adds random generation, streaming, and std::error::Error
+#![no_std]
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("lb64 requires either the \"std\" or \"alloc\" feature");
+
+extern crate alloc;
+#[cfg(feature = "std")]
 extern crate rand;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[cfg(feature = "std")]
 use rand::prelude::*;
 
-use std::cmp::Ordering;
-use std::cmp::PartialEq;
-use std::fmt::{Display, Formatter};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::cmp::PartialEq;
+use core::convert::TryFrom;
+use core::fmt::{self, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Index;
+use core::str::FromStr;
 
+/// Arithmetic on Base64 numbers
+mod arithmetic;
+/// Conversions to and from the output of other Base64 libraries, enabled by the `compat` feature
+#[cfg(feature = "compat")]
+mod compat;
 /// Creation of custom configs for Base64 numbers containing different characters, with or without
-/// padding, with or without a maximum line length. In addition, 5 configs are already defined
-/// because of their popularity (`STANDARD`, `MIME`, `IMAP`, `URLSAFE` with and without padding).
+/// padding, with or without a maximum line length. In addition, 10 configs are already defined
+/// because of their popularity (`STANDARD`, `MIME`, `PEM`, `IMAP`, `BCRYPT`, `CRYPT`, `Y64`,
+/// `XML_NMTOKEN`, `URLSAFE` with and without padding).
 pub mod config;
 /// Decoding functions for Base64
 mod decode;
@@ -66,17 +90,65 @@ mod decode;
 mod encode;
 /// Enums for Errors that can occur when making a Config or when decoding
 pub mod error;
+/// Serialize and Deserialize implementations for Base64, enabled by the `serde` feature
+#[cfg(feature = "serde")]
+mod serde_impl;
+/// Vectorized encoding and decoding for configs with ASCII character sets, enabled by the `simd`
+/// feature
+#[cfg(feature = "simd")]
+mod simd;
+/// Streaming adapters for encoding data with std::io, enabled by the `std` feature
+#[cfg(feature = "std")]
+pub mod stream;
+/// Borrowed read only view of a Base64 number
+mod view;
+
+/// Order of the bytes when encoding and decoding with
+/// [new_encode_bytes_with_order](struct.Base64.html#method.new_encode_bytes_with_order) and
+/// [decode_to_bytes_with_order](struct.Base64.html#method.decode_to_bytes_with_order)
+///
+/// Implements Equals, Debug, Clone, Copy, and Default which is
+/// [BigEndian](enum.ByteOrder.html#variant.BigEndian)
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Default)]
+pub enum ByteOrder {
+    /// The bytes are encoded in the order they're provided, like
+    /// [new_encode_bytes](struct.Base64.html#method.new_encode_bytes)
+    #[default]
+    BigEndian,
+    /// The bytes are reversed before they're encoded and after they're decoded
+    LittleEndian,
+}
 
 /// Base64 number
 ///
-/// value: a Vec<char> representing the value of the Base64 number
+/// value: a String representing the value of the Base64 number, stored as UTF-8 so each
+/// character of an ASCII character set only takes a single byte
 ///
 /// conf: the config specific for this Base64 number
 ///
-/// Implements Clone, Debug, Eqs, and Compare
-#[derive(Eq, Debug, Clone)]
+/// bytes: whether the value is encoded bytes, only encoded bytes are padded because padding
+/// fills out a group of 3 bytes, numbers are never padded
+///
+/// Implements Clone, Debug, Eqs, Hash, and Compare
+#[derive(Eq, Clone)]
 pub struct Base64<'a> {
-    value: Vec<char>,
+    value: String,
+    conf: &'a config::Config<'a>,
+    bytes: bool,
+}
+
+/// Borrowed read only view of a [Base64](struct.Base64.html) number created with
+/// [as_ref_view](struct.Base64.html#method.as_ref_view), for passing the number around without
+/// cloning it
+///
+/// value: the characters of the Base64 number
+///
+/// conf: the config of the Base64 number
+///
+/// Implements Clone, Copy, Debug, and Display
+#[derive(Debug, Clone, Copy)]
+pub struct Base64Ref<'a> {
+    value: &'a str,
     conf: &'a config::Config<'a>,
 }
 
@@ -97,19 +169,22 @@ impl<'a> Base64<'a> {
     ///     println!("{}", b64);
     /// }
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Self {
         Base64 {
-            value: vec!['A'],
+            value: String::from("A"),
             conf: config::STANDARD,
+            bytes: false,
         }
     }
 
-    /// Creates a random base64 number of at least the provided length.
+    /// Creates a random base64 number of at least the provided length. Requires the `std` feature
     ///
     /// # Parameters:
     /// new length of base64 number and the configuration struct, Note if the configuration
     /// specifies padding then the length may be higher if the length specififed isn't divisible by
-    /// 4
+    /// 4. A length of 0 creates 0 ("A") like [default](struct.Base64.html#method.default) rather
+    /// than an empty Base64 number
     ///
     /// #Returns:
     /// the new random base64 number
@@ -127,20 +202,51 @@ impl<'a> Base64<'a> {
     ///     println!("{}", b64); // Since there's padding then the length will be divisible by 4 therefore length 8
     /// }
     /// ```
+    #[cfg(feature = "std")]
     pub fn new_random(len: usize, conf: &'a config::Config<'a>) -> Self {
-        let mut val: Vec<char> = Vec::new();
-        for _i in 0..len {
-            val.push(generate_base64(conf.get_character_set()));
-        }
-        let mut b64 = Base64 { value: val, conf };
-        b64.add_padding(); // Add padding if necessary
+        Base64::new_random_with(len, conf, &mut thread_rng())
+    }
+
+    /// Creates a random base64 number of at least the provided length like
+    /// [new_random](struct.Base64.html#method.new_random), using the provided random number
+    /// generator so a seeded generator gives the same value every time. Requires the `std` feature
+    ///
+    /// # Parameters:
+    /// new length of base64 number, the configuration struct, and the random number generator
+    ///
+    /// #Returns:
+    /// the new random base64 number
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// extern crate rand;
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// fn main() {
+    ///     let mut rng = StdRng::seed_from_u64(7);
+    ///     let b64 = Base64::new_random_with(5, URL_SAFE_NO_PADDING, &mut rng);
+    ///     println!("{}", b64); // Prints the same value every time
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn new_random_with<R: Rng>(len: usize, conf: &'a config::Config<'a>, rng: &mut R) -> Self {
+        let mut b64 = Base64 {
+            value: String::new(),
+            conf,
+            bytes: true,
+        };
+        b64.set_random_with(len, rng);
         b64
     }
 
-    /// Sets the value of the Base64 number to a random value.  Param: len, length for base64 number
+    /// Sets the value of the Base64 number to a random value. Requires the `std` feature
     ///
     /// # Parameters:
-    /// The minimum length for the base64 number
+    /// The minimum length for the base64 number, a length of 0 sets it to 0 ("A")
     ///
     /// # Example:
     /// ```
@@ -155,334 +261,1553 @@ impl<'a> Base64<'a> {
     ///     println!("{}", b64); // No padding length will now be 8
     /// }
     /// ```
+    #[cfg(feature = "std")]
     pub fn set_random(&mut self, len: usize) {
-        let mut val: Vec<char> = Vec::new();
-        for _i in 0..len {
-            val.push(generate_base64(self.conf.get_character_set()));
-        }
-        self.value = val;
-        self.add_padding();
+        self.set_random_with(len, &mut thread_rng());
     }
 
-    /// Get length of Base64 number
+    /// Sets the value of the Base64 number to a random value like
+    /// [set_random](struct.Base64.html#method.set_random) and returns it so calls can be chained.
+    /// Requires the `std` feature
     ///
-    /// # Return:
-    /// Return usize of Base64 number
+    /// # Parameters:
+    /// The minimum length for the base64 number
     ///
     /// # Example:
     /// ```
     /// extern crate lb64; // Import/Include crate
     /// use lb64::{Base64}; // Base64
-    /// use lb64::config::{URL_SAFE_NO_PADDING}; // Constant config
+    /// use lb64::config::MIME; // Constant config
     ///
     /// fn main() {
-    ///     let mut b64 = Base64::new_random(5, URL_SAFE_NO_PADDING); // Sets the length to 5 and randomly generates the values
-    ///     println!("{}", b64); // Since there's no padding then the length will be 5
-    ///     println!("{}", b64.len()); // Length of 5
+    ///     let b64 = Base64::default().with_config(MIME).with_random_len(8);
+    ///     println!("{}", b64); // Prints 8 random characters
     /// }
     /// ```
-    pub fn len(&self) -> usize {
-        self.value.len()
+    #[cfg(feature = "std")]
+    pub fn with_random_len(mut self, len: usize) -> Self {
+        self.set_random(len);
+        self
     }
 
-    /// Adds the padding character if the Base64 config has padding turned on until the number is
-    /// divisible by 4
-    fn add_padding(&mut self) {
-        if self.conf.get_padding().is_some() {
-            while self.len() % 4 != 0 {
-                self.value.push(self.conf.get_padding().unwrap());
-            }
+    /// Sets the value of the Base64 number to a random value like
+    /// [set_random](struct.Base64.html#method.set_random), using the provided random number
+    /// generator. Requires the `std` feature
+    ///
+    /// # Parameters:
+    /// The minimum length for the base64 number and the random number generator, a length of 0
+    /// sets it to 0 ("A")
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// extern crate rand;
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_random(5, URL_SAFE_NO_PADDING);
+    ///     b64.set_random_with(8, &mut StdRng::seed_from_u64(7));
+    ///     println!("{}", b64); // Prints the same value of length 8 every time
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_random_with<R: Rng>(&mut self, len: usize, rng: &mut R) {
+        if len == 0 {
+            // 0 like default instead of an empty value, it's a number so it isn't padded
+            self.value = decimal_to_base64(self.conf, 0);
+            self.bytes = false;
+            return;
+        }
+        let mut val: String = String::with_capacity(len);
+        for _i in 0..len {
+            val.extend(generate_base64(self.conf.get_character_set(), rng));
         }
+        self.value = val;
+        self.bytes = true;
+        self.add_padding();
     }
 
-    /// Sets Base64 to that String if it's valid
+    /// Creates a random base64 number of at least the provided length like
+    /// [new_random](struct.Base64.html#method.new_random), returning an error instead of
+    /// panicking if the length can't be allocated. Any length that fits is created as a padded
+    /// byte-style value. Requires the `std` feature
     ///
-    /// # Return:
-    /// If all characters are valid Base64 return Self otherwise a
-    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    /// Values longer than 21 characters don't fit in a u128, because 64^21 is the largest power
+    /// of 64 that does, so they can overflow
+    /// [decode_to_unsigned](struct.Base64.html#method.decode_to_unsigned). Use
+    /// [new_random_numeric](struct.Base64.html#method.new_random_numeric) for random numbers
+    /// that always decode
+    ///
+    /// # Parameters:
+    /// length of base64 number and the configuration struct
+    ///
+    /// # Returns:
+    /// Result of either the new random base64 number or
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError) if the
+    /// padded length can't be allocated
     ///
     /// # Example:
     /// ```
     /// extern crate lb64; // Import/Include crate
     /// use lb64::{Base64}; // Base64
-    /// use lb64::config::{URL_SAFE_NO_PADDING, URL_SAFE_PADDING}; // Constant configs
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
     ///
     /// fn main() {
-    ///     let b64 = Base64::new_from_string("Hello", URL_SAFE_PADDING); // Sets b64 to the string if the string is valid Base64
-    ///     // It returns a Result<Base64, String>
-    ///     match b64 {
-    ///         Ok(value) => println!("{}", 64), // prints "Hello===" adds padding so it's divisible by 4
-    ///         Err(e) => println!("{}", e), // The error message stating the first incorrect character
+    ///     match Base64::try_new_random(100, URL_SAFE_NO_PADDING) {
+    ///         Ok(b64) => println!("{}", b64.len()), // Prints 100
+    ///         Err(e) => println!("{}", e),
     ///     }
+    ///     println!("{}", Base64::try_new_random(usize::MAX, URL_SAFE_NO_PADDING).is_err()); // Prints true
     /// }
     /// ```
-    pub fn new_from_string(
-        new: &str,
+    #[cfg(feature = "std")]
+    pub fn try_new_random(
+        len: usize,
         conf: &'a config::Config<'a>,
     ) -> Result<Self, error::Base64Error> {
-        let mut val: Vec<char> = Vec::new();
-        for ch in new.chars() {
-            if !is_valid_base64('\0', conf.get_character_set(), ch)
-                || (conf.get_padding().is_some()
-                    && !is_valid_base64(conf.get_padding().unwrap(), conf.get_character_set(), ch))
-            {
-                return Err(error::Base64Error::InvalidBase64CharacterError);
-            } else {
-                val.push(ch);
-            }
+        if len == 0 {
+            return Ok(Base64::new_random(0, conf));
         }
-        let mut b64 = Base64 { value: val, conf };
+        // Reserve the padding as well so a length that can't be allocated fails here
+        let padded = match conf.get_padding() {
+            Some(_) => len.checked_add(3).map(|l| l / 4 * 4),
+            None => Some(len),
+        };
+        let mut val = String::new();
+        match padded {
+            Some(l) if val.try_reserve_exact(l).is_ok() => {}
+            _ => return Err(error::Base64Error::OverflowError),
+        }
+        let mut rng = thread_rng();
+        for _i in 0..len {
+            val.extend(generate_base64(conf.get_character_set(), &mut rng));
+        }
+        let mut b64 = Base64 {
+            value: val,
+            conf,
+            bytes: true,
+        };
         b64.add_padding();
         Ok(b64)
     }
 
-    /// Takes a new configuration and converts the Base64 number to that representation
+    /// Creates a base64 number of a random unsigned value from 0 to max inclusive, unlike
+    /// [new_random](struct.Base64.html#method.new_random) it always decodes with
+    /// [decode_to_unsigned](struct.Base64.html#method.decode_to_unsigned). Requires the `std`
+    /// feature
     ///
-    /// # Example
+    /// # Parameters:
+    /// the maximum value of the random number and the configuration struct
+    ///
+    /// # Returns:
+    /// the new random base64 number, which isn't padded
+    ///
+    /// # Example:
     /// ```
     /// extern crate lb64; // Import/Include crate
     /// use lb64::{Base64}; // Base64
-    /// use lb64::config::{URL_SAFE_NO_PADDING, URL_SAFE_PADDING}; // Constant configs
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
     ///
     /// fn main() {
-    ///     let mut b64 = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING); // Sets b64 to _
-    ///     b64.set_config(URL_SAFE_PADDING); // Changes configs and adds padding to _
-    ///     println!("{}", b64); // Prints _===
+    ///     let b64 = Base64::new_random_numeric(1000, URL_SAFE_NO_PADDING);
+    ///     println!("{}", b64.decode_to_unsigned().unwrap()); // Prints a value from 0 to 1000
     /// }
     /// ```
-    pub fn set_config(&mut self, conf: &'a config::Config<'a>) {
-        // If they aren't the same actually convert
-        self.value = self.convert_to_new_config(conf);
-        self.conf = conf;
-        self.add_padding();
+    #[cfg(feature = "std")]
+    pub fn new_random_numeric(max: u128, conf: &'a config::Config<'a>) -> Self {
+        let value = thread_rng().sample(rand::distributions::Uniform::new_inclusive(0, max));
+        Base64::new_encode_unsigned(&value, conf)
     }
 
-    fn convert_to_new_config(&self, conf: &'a config::Config<'a>) -> Vec<char> {
-        let mut v: Vec<char> = Vec::new();
-        for i in &self.value {
-            if self.conf.get_padding().is_some() && *i == self.conf.get_padding().unwrap() {
-                if conf.get_padding().is_some()
-                    && conf.get_padding().unwrap() == self.conf.get_padding().unwrap()
-                {
-                    // Both have padding and their padding is the same
-                    continue;
-                } else if conf.get_padding().is_some()
-                    && conf.get_padding().unwrap() != self.conf.get_padding().unwrap()
-                {
-                    // They both have padding and they're different
-                    v.push(conf.get_padding().unwrap());
-                } else {
-                    // The new configuration doesn't have padding and therefore skip it
-                    continue;
-                }
-            } else {
-                // Convert the current configuration value to it's new equivalent
-                v.push(decimal_to_base64_char(
-                    conf.get_character_set(),
-                    base64_char_to_decimal(self.conf.get_character_set(), *i),
-                ));
-            }
+    /// Creates a Base64 number with no digits, unlike [default](struct.Base64.html#method.default)
+    /// which is "A". Padding is only added once there are digits, so it stays empty with a config
+    /// that has padding
+    ///
+    /// # Parameters:
+    /// the configuration struct
+    ///
+    /// # Returns:
+    /// the new empty Base64 number, which decodes to 0
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::empty(URL_SAFE_PADDING);
+    ///     println!("{}", b64.is_empty()); // Prints true
+    ///     b64.increment();
+    ///     println!("{}", b64); // Prints B
+    /// }
+    /// ```
+    pub fn empty(conf: &'a config::Config<'a>) -> Self {
+        Base64 {
+            value: String::new(),
+            conf,
+            bytes: false,
         }
-        v
     }
 
-    /// Sets the Base64 value to a given String
+    /// Get length of Base64 number
     ///
     /// # Return:
-    /// false if any value is invalid
+    /// Return usize of Base64 number
     ///
     /// # Example:
     /// ```
     /// extern crate lb64; // Import/Include crate
     /// use lb64::{Base64}; // Base64
-    /// use lb64::config::{URL_SAFE_NO_PADDING, URL_SAFE_PADDING}; // Constant configs
+    /// use lb64::config::{URL_SAFE_NO_PADDING}; // Constant config
     ///
     /// fn main() {
-    ///     let mut b64 = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING); // Sets b64 to _
-    ///     b64.set_config(URL_SAFE_PADDING); // Changes configs and adds padding to _
-    ///     println!("{}", b64); // Prints _===
+    ///     let mut b64 = Base64::new_random(5, URL_SAFE_NO_PADDING); // Sets the length to 5 and randomly generates the values
+    ///     println!("{}", b64); // Since there's no padding then the length will be 5
+    ///     println!("{}", b64.len()); // Length of 5
     /// }
     /// ```
-    pub fn set_from_string(&mut self, new: &str) -> bool {
-        let mut val: Vec<char> = Vec::new();
-        for ch in new.chars() {
-            if (self.conf.get_padding().is_none()
-                && is_valid_base64('\0', self.conf.get_character_set(), ch))
-                || (self.conf.get_padding().is_some()
-                    && is_valid_base64(
-                        self.conf.get_padding().unwrap(),
-                        self.conf.get_character_set(),
-                        ch,
-                    ))
-            {
-                val.push(ch);
-            } else {
-                return false;
-            }
-        }
-        self.value = val;
-        self.add_padding();
-        true
+    pub fn len(&self) -> usize {
+        self.value.chars().count()
     }
 
-    /// Extends base 64 number by prepending As to it to fit a new size
-    ///
-    /// # Parameters:
-    /// len, the new size of the base64 value
+    /// Checks if the Base64 number has no characters, such as one created by
+    /// [empty](struct.Base64.html#method.empty)
     ///
     /// # Example:
     /// ```
     /// extern crate lb64; // Import/Include crate
     /// use lb64::{Base64}; // Base64
-    /// use lb64::config::{URL_SAFE_NO_PADDING, URL_SAFE_PADDING}; // Constant configs
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
     ///
     /// fn main() {
-    ///     let mut b64 = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING); // Sets b64 to _
-    ///     b64.set_config(URL_SAFE_PADDING); // Changes configs and adds padding to _
-    ///     println!("{}", b64); // Prints _===
+    ///     println!("{}", Base64::empty(URL_SAFE_NO_PADDING).is_empty()); // Prints true
+    ///     println!("{}", Base64::default().is_empty()); // Prints false
     /// }
     /// ```
-    pub fn expand_to(&mut self, len: usize) {
-        while self.value.len() < len {
-            self.value.push('A');
-        }
-        self.value.reverse();
-        self.add_padding();
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
     }
 
-    /// Truncates base64 number be removing the most significant values until it fits the new size
+    /// Counts the digits starting at the most significant non-zero digit, so leading As (the 0
+    /// value of the character set) such as those added by
+    /// [expand_to](struct.Base64.html#method.expand_to) aren't counted. Padding, newlines, and
+    /// spaces aren't counted either
     ///
-    /// # Parameters:
-    /// len, the new size of the base64 value. Must be greater than 0
+    /// # Return:
+    /// the number of significant digits, 1 if the value is 0
     ///
     /// # Example:
     /// ```
     /// extern crate lb64; // Import/Include crate
     /// use lb64::{Base64}; // Base64
-    /// use lb64::config::{URL_SAFE_PADDING, URL_SAFE_NO_PADDING}; // Constant configs
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
     ///
     /// fn main() {
-    ///     let mut b64 = Base64::new_encode_unsigned(&63, URL_SAFE_PADDING); // Sets b64 to _===
-    ///     println!("{}", b64); // Prints _===
-    ///     b64.truncate_to(2); // This does essentially nothing because padding is required and therefore it must be divisible by 4
-    ///     println!("{}", b64); // Prints _=== stil
-    ///     let mut b64 = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING); // Sets b64 to _
-    ///     b64.truncate_to(1); // Length is already 1 so it remains _
+    ///     let b64 = Base64::new_encode_unsigned_min_width(&10, 3, URL_SAFE_NO_PADDING); // Sets b64 to AAK
+    ///     println!("{}", b64.len()); // Prints 3
+    ///     println!("{}", b64.significant_len()); // Prints 1
     /// }
     /// ```
-    pub fn truncate_to(&mut self, len: usize) {
-        if len > 0 && self.value.len() > len {
-            self.value.reverse(); // flip to remove most significant values
-            while self.value.len() > len {
-                self.value.pop();
-            }
-            self.value.reverse(); // flip back
-            self.add_padding(); // Add padding
-        }
+    pub fn significant_len(&self) -> usize {
+        self.digits().skip_while(|d| *d == 0).count().max(1)
     }
-}
 
-/// Generates values from 0 to 63 and returns the character corresponding to it
-fn generate_base64(a: &[char]) -> char {
-    decimal_to_base64_char(a, thread_rng().gen_range(0, 64) as u128)
-}
+    /// Get the character at the provided index, index 0 being the most significant, without
+    /// panicing
+    ///
+    /// # Return:
+    /// Some(char) if the index is within the Base64 number otherwise None
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING); // Sets b64 to "CA"
+    ///     println!("{:?}", b64.get(0)); // Prints Some('C')
+    ///     println!("{:?}", b64.get(2)); // Prints None
+    /// }
+    /// ```
+    pub fn get(&self, i: usize) -> Option<char> {
+        self.value.chars().nth(i)
+    }
 
-/// Checks if a character is a valid value in Base64
-/// Param: val, the character to check as a u8
-/// Return: true if it's value false otherwise
-fn is_valid_base64(pad: char, a: &[char], val: char) -> bool {
-    if val == '\n' || val == ' ' || val == pad {
-        return true;
-    } else {
-        for i in a.iter() {
-            if val == *i {
-                return true;
-            }
-        }
+    /// Iterator over the characters of the Base64 number, including padding
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_PADDING); // Sets b64 to "SGk="
+    ///     for c in b64.chars() {
+    ///         println!("{}", c); // Prints 'S', 'G', 'k', '='
+    ///     }
+    /// }
+    /// ```
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.value.chars()
     }
-    false
-}
 
-/// Convert decimal value to base64 by mod 64 to get the base64 place and then dividing
-/// by 64 to get the value
-/// Param: value, the value to convert
-/// Return Vector of chars that is the Base64 value
-pub(crate) fn decimal_to_base64(conf: &config::Config, mut value: u128) -> Vec<char> {
-    let mut v: Vec<char> = Vec::new();
-    while value > 0 {
-        let base64_val = value % 64;
-        value /= 64;
-        v.push(decimal_to_base64_char(conf.get_character_set(), base64_val));
+    /// Borrows the characters of the Base64 number, including padding and line endings, without
+    /// allocating. The value is stored as UTF-8 so it's a &str rather than a slice of chars
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_PADDING); // Sets b64 to "SGk="
+    ///     println!("{}", b64.as_str()); // Prints SGk=
+    /// }
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.value
     }
-    v.reverse(); // Reverse to get into proper order
-    v
-}
 
-/// Converts a decimal value to it's base 64 value
-/// Param: value, the value to convert
-/// Return: the character corresponding to the decimal in Base64
-pub(crate) fn decimal_to_base64_char(a: &[char], value: u128) -> char {
-    a[value as usize]
-}
+    /// Borrows the characters of the Base64 number as a Cow, for code that only sometimes needs
+    /// an owned String. The value is already stored as a String so this never allocates, call
+    /// into_owned on the Cow to get a String
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_PADDING); // Sets b64 to "SGk="
+    ///     let s: String = b64.as_cow_str().into_owned();
+    ///     println!("{}", s); // Prints SGk=
+    /// }
+    /// ```
+    pub fn as_cow_str(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.value)
+    }
 
-/// Converts a char to it's corresponding u128 value in base64
-/// Param: value, char to convert
-/// Return: u128, the value of the char in base64
-pub(crate) fn base64_char_to_decimal(a: &[char], c: char) -> u128 {
-    for (i, val) in a.iter().enumerate() {
-        if c == *val {
-            return i as u128;
-        }
+    /// Iterator over the decimal value (0 to 63) of each digit of the Base64 number, padding,
+    /// newlines, and spaces are skipped
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_unsigned(&128, URL_SAFE_PADDING); // Sets b64 to "CA=="
+    ///     for d in b64.digits() {
+    ///         println!("{}", d); // Prints 2, 0
+    ///     }
+    /// }
+    /// ```
+    pub fn digits(&self) -> impl Iterator<Item = u128> + '_ {
+        let pad = self.conf.get_padding();
+        self.value
+            .chars()
+            .filter(move |c| Some(*c) != pad && *c != '\n' && *c != '\r' && *c != ' ')
+            .map(move |c| base64_char_to_decimal(self.conf.get_character_set(), c))
     }
-    0 // Not Possible
-}
 
-impl<'a> Display for Base64<'a> {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        let mut print: String = String::new();
-        for i in &self.value {
-            print.push(*i);
+    /// Adds the padding character if the Base64 config has padding turned on and the value is
+    /// encoded bytes until the number of digits and padding is divisible by 4, any trailing
+    /// padding is replaced so the count is always correct
+    fn add_padding(&mut self) {
+        if !self.bytes {
+            return;
+        }
+        if let Some(pad) = self.conf.get_padding() {
+            while self.value.ends_with(pad) {
+                self.value.pop();
+            }
+            let mut len = self.digits().count();
+            while !len.is_multiple_of(4) {
+                self.value.push(pad);
+                len += 1;
+            }
         }
-        write!(f, "{}", print)
     }
-}
 
-impl<'a> PartialEq for Base64<'a> {
-    fn eq(&self, other: &Base64) -> bool {
-        if self.value.len() != other.value.len() {
-            return false;
-        } else {
-            for i in 0..self.value.len() {
-                if self.value[i] != other.value[i] {
-                    return false;
+    /// Creates a copy of the Base64 number without any padding characters, the copy has the same
+    /// config
+    ///
+    /// # Return:
+    /// the Base64 number without padding
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME); // Sets b64 to SGk=
+    ///     println!("{}", b64.without_padding()); // Prints SGk
+    /// }
+    /// ```
+    pub fn without_padding(&self) -> Base64<'a> {
+        let mut b64 = self.clone();
+        b64.strip_padding();
+        b64
+    }
+
+    /// Removes all padding characters from the Base64 number
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME); // Sets b64 to SGk=
+    ///     b64.strip_padding();
+    ///     println!("{}", b64); // Prints SGk
+    /// }
+    /// ```
+    pub fn strip_padding(&mut self) {
+        self.value = decode::remove_padding(self.conf.get_padding(), &self.value);
+    }
+
+    /// Computes the number of bytes the Base64 number decodes to, every 4 digits are 3 bytes and
+    /// any leftover bits are ignored
+    ///
+    /// # Return:
+    /// the number of bytes [decode_to_bytes](struct.Base64.html#method.decode_to_bytes) returns
+    /// for a valid Base64 number
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hello".as_bytes(), MIME); // Sets b64 to SGVsbG8=
+    ///     println!("{}", b64.decoded_len()); // Prints 5
+    /// }
+    /// ```
+    pub fn decoded_len(&self) -> usize {
+        self.digits().count() * 6 / 8
+    }
+
+    /// Checks if the Base64 number ends in padding
+    ///
+    /// # Return:
+    /// true if the config has padding and there's at least one trailing padding character
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::{MIME, URL_SAFE_NO_PADDING}; // Constant configs
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME); // Sets b64 to SGk=
+    ///     println!("{}", b64.is_padded()); // Prints true
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_NO_PADDING); // Sets b64 to SGk
+    ///     println!("{}", b64.is_padded()); // Prints false
+    /// }
+    /// ```
+    pub fn is_padded(&self) -> bool {
+        self.padding_count() > 0
+    }
+
+    /// Counts the trailing padding characters, padding anywhere else isn't counted
+    ///
+    /// # Return:
+    /// the number of trailing padding characters, 0 if the config doesn't have padding
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("H".as_bytes(), MIME); // Sets b64 to SA==
+    ///     println!("{}", b64.padding_count()); // Prints 2
+    /// }
+    /// ```
+    pub fn padding_count(&self) -> usize {
+        match self.conf.get_padding() {
+            Some(pad) => self.value.chars().rev().take_while(|c| *c == pad).count(),
+            None => 0,
+        }
+    }
+
+    /// Checks if the trailing padding isn't the canonical amount, for example after
+    /// [strip_padding](struct.Base64.html#method.strip_padding). Only encoded bytes are padded so
+    /// numbers and configs without padding never need padding
+    ///
+    /// # Return:
+    /// true if [restore_padding](struct.Base64.html#method.restore_padding) would change the
+    /// Base64 number
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME); // Sets b64 to SGk=
+    ///     println!("{}", b64.needs_padding()); // Prints false
+    ///     b64.strip_padding();
+    ///     println!("{}", b64.needs_padding()); // Prints true
+    /// }
+    /// ```
+    pub fn needs_padding(&self) -> bool {
+        if !self.bytes || self.conf.get_padding().is_none() {
+            return false;
+        }
+        let digits = self.digits().count();
+        self.padding_count() != (4 - digits % 4) % 4
+    }
+
+    /// Replaces the trailing padding with the canonical amount so the number of digits and
+    /// padding is divisible by 4, missing padding is added and extra padding is removed. Only
+    /// encoded bytes are padded, numbers and configs without padding are unchanged
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME); // Sets b64 to SGk=
+    ///     b64.strip_padding();
+    ///     println!("{}", b64); // Prints SGk
+    ///     b64.restore_padding();
+    ///     println!("{}", b64); // Prints SGk=
+    /// }
+    /// ```
+    pub fn restore_padding(&mut self) {
+        self.add_padding();
+    }
+
+    /// Sets Base64 to that String if it's valid, spaces and newlines are only valid when the config
+    /// has a line length such as MIME
+    ///
+    /// # Return:
+    /// If all characters are valid Base64 return Self otherwise a
+    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::{URL_SAFE_NO_PADDING, URL_SAFE_PADDING}; // Constant configs
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_from_string("Hello", URL_SAFE_PADDING); // Sets b64 to the string if the string is valid Base64
+    ///     // It returns a Result<Base64, String>
+    ///     match b64 {
+    ///         Ok(value) => println!("{}", 64), // prints "Hello===" adds padding so it's divisible by 4
+    ///         Err(e) => println!("{}", e), // The error message stating the first incorrect character
+    ///     }
+    /// }
+    /// ```
+    pub fn new_from_string(
+        new: &str,
+        conf: &'a config::Config<'a>,
+    ) -> Result<Self, error::Base64Error> {
+        validate(new, conf)?;
+        let mut b64 = Base64 {
+            value: new.chars().map(|c| conf.canonical(c)).collect(),
+            conf,
+            bytes: true,
+        };
+        b64.add_padding();
+        Ok(b64)
+    }
+
+    /// Creates a Base64 number from a &str taken from a URL where the padding may be percent
+    /// encoded, every %3D or %3d is replaced with the padding character of the config and then
+    /// it's validated like [new_from_string](struct.Base64.html#method.new_from_string). Any
+    /// other percent encoded character is invalid
+    ///
+    /// # Return:
+    /// If all characters are valid Base64 return Self otherwise a
+    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError),
+    /// the index is in the &str after the padding was replaced
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     match Base64::new_from_url_string("SGk%3D", URL_SAFE_PADDING) {
+    ///         Ok(value) => println!("{}", value), // Prints SGk=
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn new_from_url_string(
+        new: &str,
+        conf: &'a config::Config<'a>,
+    ) -> Result<Self, error::Base64Error> {
+        let pad = match conf.get_padding() {
+            Some(pad) => pad,
+            // Without padding %3D is invalid like any other percent encoded character
+            None => return Base64::new_from_string(new, conf),
+        };
+        let mut unescaped: String = String::with_capacity(new.len());
+        let mut rest = new;
+        while let Some(i) = rest.find('%') {
+            unescaped.push_str(&rest[..i]);
+            rest = &rest[i..];
+            if rest.starts_with("%3D") || rest.starts_with("%3d") {
+                unescaped.push(pad);
+                rest = &rest[3..];
+            } else {
+                // Keep the % so it's reported as an invalid character
+                unescaped.push('%');
+                rest = &rest[1..];
+            }
+        }
+        unescaped.push_str(rest);
+        Base64::new_from_string(&unescaped, conf)
+    }
+
+    /// Returns the configuration of the Base64 number, it lives as long as the Base64 number's
+    /// configuration so it can be used to encode other values the same way
+    ///
+    /// # Example
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant configs
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME); // Sets b64 to SGk=
+    ///     println!("{:?}", b64.config().get_padding()); // Prints Some('=')
+    ///     let other = Base64::new_encode_bytes("Hello".as_bytes(), b64.config()); // Sets other to SGVsbG8=
+    ///     println!("{}", other); // Prints SGVsbG8=
+    /// }
+    /// ```
+    pub fn config(&self) -> &'a config::Config<'a> {
+        self.conf
+    }
+
+    /// Takes a new configuration and converts the Base64 number to that representation, each digit
+    /// keeps its value (0 to 63) and is written with the character of the new character set so
+    /// the number is the same. For example '+' is 62 in STANDARD so it becomes '-' in
+    /// URL_SAFE_NO_PADDING. The characters aren't kept, see
+    /// [reinterpret_config](struct.Base64.html#method.reinterpret_config) which keeps the
+    /// characters and reads them with the new character set instead
+    ///
+    /// # Example
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::{URL_SAFE_NO_PADDING, URL_SAFE_PADDING}; // Constant configs
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_NO_PADDING); // Sets b64 to SGk
+    ///     b64.set_config(URL_SAFE_PADDING); // Changes configs and adds padding to SGk
+    ///     println!("{}", b64); // Prints SGk=
+    ///     let mut b64 = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING); // Sets b64 to _
+    ///     b64.set_config(URL_SAFE_PADDING); // Numbers aren't padded
+    ///     println!("{}", b64); // Prints _
+    /// }
+    /// ```
+    pub fn set_config(&mut self, conf: &'a config::Config<'a>) {
+        // If they aren't the same actually convert
+        self.value = self.convert_to_new_config(conf);
+        self.conf = conf;
+        self.add_padding();
+    }
+
+    /// Converts the Base64 number to the new configuration like
+    /// [set_config](struct.Base64.html#method.set_config) and returns it so calls can be chained
+    ///
+    /// # Example
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::default().with_config(MIME); // 0 with MIME instead of STANDARD
+    ///     println!("{}", b64); // Prints A
+    /// }
+    /// ```
+    pub fn with_config(mut self, conf: &'a config::Config<'a>) -> Self {
+        self.set_config(conf);
+        self
+    }
+
+    /// Takes a new configuration and keeps the characters of the Base64 number, each character is
+    /// read with the new character set so the number can change. For example 'A' is 0 in STANDARD
+    /// and stays 'A' in CRYPT where it means 12.
+    /// [set_config](struct.Base64.html#method.set_config) does the opposite, it keeps the digits
+    /// and changes the characters so 'A' would become '.'
+    ///
+    /// Padding of the old config is removed and padding of the new config is added. Nothing is
+    /// changed if a character isn't part of the new config
+    ///
+    /// # Parameters:
+    /// the new configuration struct
+    ///
+    /// # Return:
+    /// Result of either () or
+    /// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+    /// with the first character that isn't part of the new config
+    ///
+    /// # Example
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::{CRYPT, STANDARD, URL_SAFE_NO_PADDING}; // Constant configs
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned(&0, STANDARD); // Sets b64 to A
+    ///     b64.reinterpret_config(CRYPT).unwrap();
+    ///     println!("{}", b64); // Prints A
+    ///     println!("{:?}", b64.decode_to_unsigned()); // Prints Ok(12)
+    ///     let mut b64 = Base64::new_encode_unsigned(&62, STANDARD); // Sets b64 to +
+    ///     println!("{}", b64.reinterpret_config(URL_SAFE_NO_PADDING).is_err()); // Prints true
+    ///     println!("{}", b64); // Prints +
+    /// }
+    /// ```
+    pub fn reinterpret_config(
+        &mut self,
+        conf: &'a config::Config<'a>,
+    ) -> Result<(), error::Base64Error> {
+        let stripped = decode::remove_padding(self.conf.get_padding(), &self.value);
+        validate(&stripped, conf)?;
+        self.value = stripped.chars().map(|c| conf.canonical(c)).collect();
+        self.conf = conf;
+        self.add_padding();
+        Ok(())
+    }
+
+    fn convert_to_new_config(&self, conf: &'a config::Config<'a>) -> String {
+        let mut v: String = String::with_capacity(self.value.len());
+        for i in self.value.chars() {
+            if self.conf.get_padding().is_some() && i == self.conf.get_padding().unwrap() {
+                if conf.get_padding().is_some()
+                    && conf.get_padding().unwrap() == self.conf.get_padding().unwrap()
+                {
+                    // Both have padding and their padding is the same
+                    continue;
+                } else if conf.get_padding().is_some()
+                    && conf.get_padding().unwrap() != self.conf.get_padding().unwrap()
+                {
+                    // They both have padding and they're different
+                    v.push(conf.get_padding().unwrap());
+                } else {
+                    // The new configuration doesn't have padding and therefore skip it
+                    continue;
                 }
+            } else if i == '\n' || i == '\r' || i == ' ' {
+                // Line endings and spaces aren't values so keep them as is
+                v.push(i);
+            } else {
+                // Convert the current configuration value to it's new equivalent
+                v.extend(decimal_to_base64_char(
+                    conf.get_character_set(),
+                    base64_char_to_decimal(self.conf.get_character_set(), i),
+                ));
+            }
+        }
+        v
+    }
+
+    /// Sets the Base64 value to a given String
+    ///
+    /// # Return:
+    /// false if any value is invalid
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::{URL_SAFE_NO_PADDING, URL_SAFE_PADDING}; // Constant configs
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING); // Sets b64 to _
+    ///     b64.set_config(URL_SAFE_PADDING); // Changes configs
+    ///     b64.set_from_string("SGk"); // Sets b64 to SGk and adds padding
+    ///     println!("{}", b64); // Prints SGk=
+    /// }
+    /// ```
+    pub fn set_from_string(&mut self, new: &str) -> bool {
+        if validate(new, self.conf).is_err() {
+            return false;
+        }
+        self.value = new.chars().map(|c| self.conf.canonical(c)).collect();
+        self.bytes = true;
+        self.add_padding();
+        true
+    }
+
+    /// Extends base 64 number by prepending As (the 0 value of the character set) to it to fit a
+    /// new size, the order of the existing values is kept
+    ///
+    /// # Parameters:
+    /// len, the new size of the base64 value not including padding
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING); // Sets b64 to CA
+    ///     b64.expand_to(4); // Prepends As until the length is 4
+    ///     println!("{}", b64); // Prints AACA
+    /// }
+    /// ```
+    pub fn expand_to(&mut self, len: usize) {
+        if let Some(pad) = self.conf.get_padding() {
+            // Remove padding so only values are counted
+            self.value.retain(|c| c != pad);
+        }
+        let mut expanded: String = String::new();
+        if let Some(zero) = decimal_to_base64_char(self.conf.get_character_set(), 0) {
+            for _i in self.len()..len {
+                expanded.push(zero);
+            }
+        }
+        expanded.push_str(&self.value);
+        self.value = expanded;
+        self.add_padding();
+    }
+
+    /// Truncates base64 number be removing the most significant values until it fits the new size
+    ///
+    /// # Parameters:
+    /// len, the new size of the base64 value. Must be greater than 0
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned(&4095, URL_SAFE_NO_PADDING); // Sets b64 to __
+    ///     b64.truncate_to(1); // Removes the most significant _
+    ///     println!("{}", b64); // Prints _
+    ///     let mut b64 = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING); // Sets b64 to _
+    ///     b64.truncate_to(1); // Length is already 1 so it remains _
+    /// }
+    /// ```
+    pub fn truncate_to(&mut self, len: usize) {
+        let current_len = self.len();
+        if len > 0 && current_len > len {
+            // skip the most significant values
+            self.value = self.value.chars().skip(current_len - len).collect();
+            self.add_padding(); // Add padding
+        }
+    }
+
+    /// Removes the leading As (the 0 value of the character set) from the Base64 number, undoing
+    /// [expand_to](struct.Base64.html#method.expand_to), at least one digit is always left so 0 is
+    /// "A". Padding is added again if necessary
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let mut b64 = Base64::new_encode_unsigned_min_width(&10, 3, URL_SAFE_NO_PADDING); // Sets b64 to AAK
+    ///     b64.trim_leading_zeros();
+    ///     println!("{}", b64); // Prints K
+    /// }
+    /// ```
+    pub fn trim_leading_zeros(&mut self) {
+        if let Some(pad) = self.conf.get_padding() {
+            // Remove padding so only values are counted
+            self.value.retain(|c| c != pad);
+        }
+        let zero = decimal_to_base64_char(self.conf.get_character_set(), 0);
+        let leading = self.value.chars().take_while(|c| Some(*c) == zero).count();
+        // Keep the last digit when every digit is 0
+        let skip = leading.min(self.len().saturating_sub(1));
+        self.value = self.value.chars().skip(skip).collect();
+        self.add_padding();
+    }
+}
+
+/// Computes the length in characters of the Base64 String for input_len bytes encoded with the
+/// config, including padding and line endings. See
+/// [checked_encoded_len](fn.checked_encoded_len.html) to detect lengths that don't fit in a usize
+///
+/// # Parameters:
+/// input_len, the number of bytes to encode
+///
+/// conf, the config the bytes are encoded with
+///
+/// # Return:
+/// the number of characters, usize::MAX if it doesn't fit in a usize
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::config::{MIME, URL_SAFE_NO_PADDING};
+///
+/// fn main() {
+///     println!("{}", lb64::encoded_len(5, MIME)); // Prints 8
+///     println!("{}", lb64::encoded_len(5, URL_SAFE_NO_PADDING)); // Prints 7
+/// }
+/// ```
+pub fn encoded_len(input_len: usize, conf: &config::Config) -> usize {
+    checked_encoded_len(input_len, conf).unwrap_or(usize::MAX)
+}
+
+/// Computes the length in characters of the Base64 String for input_len bytes encoded with the
+/// config like [encoded_len](fn.encoded_len.html), checking for overflow
+///
+/// # Parameters:
+/// input_len, the number of bytes to encode
+///
+/// conf, the config the bytes are encoded with
+///
+/// # Return:
+/// the number of characters or None if it doesn't fit in a usize
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::config::STANDARD;
+///
+/// fn main() {
+///     println!("{:?}", lb64::checked_encoded_len(5, STANDARD)); // Prints Some(8)
+///     println!("{:?}", lb64::checked_encoded_len(usize::MAX, STANDARD)); // Prints None
+/// }
+/// ```
+pub fn checked_encoded_len(input_len: usize, conf: &config::Config) -> Option<usize> {
+    // Every 3 bytes are 4 digits, the last 1 or 2 bytes are 2 or 3 digits
+    let groups = (input_len / 3).checked_mul(4)?;
+    let digits = groups.checked_add([0, 2, 3][input_len % 3])?;
+    let len = match conf.get_padding() {
+        Some(_) => input_len.div_ceil(3).checked_mul(4)?,
+        None => digits,
+    };
+    match conf.get_line_length() {
+        // A line ending is only added before a digit once a line is full
+        Some(line) if line != 0 && digits > 0 => {
+            let endings = (digits - 1) / line;
+            len.checked_add(endings.checked_mul(conf.get_line_ending().as_str().chars().count())?)
+        }
+        _ => Some(len),
+    }
+}
+
+/// Encodes the provided bytes into a Base64 String using the
+/// [STANDARD](config/constant.STANDARD.html) config
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+///
+/// fn main() {
+///     let encoded = lb64::encode(b"Hello");
+///     println!("{}", encoded); // Prints SGVsbG8=
+/// }
+/// ```
+pub fn encode(input: &[u8]) -> String {
+    encode_config(input, config::STANDARD)
+}
+
+/// Encodes the provided bytes into a Base64 String using the provided config
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::config::URL_SAFE_NO_PADDING;
+///
+/// fn main() {
+///     let encoded = lb64::encode_config(b"Hello", URL_SAFE_NO_PADDING);
+///     println!("{}", encoded); // Prints SGVsbG8
+/// }
+/// ```
+pub fn encode_config(input: &[u8], conf: &config::Config) -> String {
+    Base64::new_encode_bytes(input, conf).to_string()
+}
+
+/// Lazily encodes the provided bytes using the provided config, each group of 3 bytes is encoded
+/// when its characters are needed instead of building the whole String. Yields the same
+/// characters as [Base64::new_encode_bytes](struct.Base64.html#method.new_encode_bytes) including
+/// padding and line endings
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::config::STANDARD;
+///
+/// fn main() {
+///     for c in lb64::encode_iter(b"Hi", STANDARD) {
+///         println!("{}", c); // Prints 'S', 'G', 'k', '='
+///     }
+/// }
+/// ```
+pub fn encode_iter<'b>(
+    bytes: &'b [u8],
+    conf: &'b config::Config<'b>,
+) -> impl Iterator<Item = char> + 'b {
+    encode::EncodeIter::new(bytes, conf)
+}
+
+/// Encodes the provided bytes into the provided buffer of characters using the provided config
+/// without allocating, use [encoded_len](fn.encoded_len.html) to size the buffer. The characters
+/// are the same as [Base64::new_encode_bytes](struct.Base64.html#method.new_encode_bytes)
+/// including padding and line endings, characters after them are left as they are
+///
+/// # Return:
+/// Result with either the number of characters written or
+/// [Base64Error::BufferTooSmall](error/enum.Base64Error.html#variant.BufferTooSmall) if out is
+/// shorter than [encoded_len](fn.encoded_len.html)
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::config::STANDARD;
+///
+/// fn main() {
+///     let mut out = ['\0'; 8];
+///     match lb64::encode_to_slice(b"Hi", STANDARD, &mut out) {
+///         Ok(written) => println!("{:?}", &out[..written]), // Prints ['S', 'G', 'k', '=']
+///         Err(e) => println!("{}", e),
+///     }
+/// }
+/// ```
+pub fn encode_to_slice(
+    input: &[u8],
+    conf: &config::Config,
+    out: &mut [char],
+) -> Result<usize, error::Base64Error> {
+    match checked_encoded_len(input.len(), conf) {
+        Some(len) if out.len() >= len => {}
+        // No buffer can hold a length that doesn't fit in a usize
+        _ => return Err(error::Base64Error::BufferTooSmall),
+    }
+    let mut written = 0;
+    for (slot, c) in out.iter_mut().zip(encode_iter(input, conf)) {
+        *slot = c;
+        written += 1;
+    }
+    Ok(written)
+}
+
+/// Decodes the provided Base64 &str into bytes using the
+/// [STANDARD](config/constant.STANDARD.html) config
+///
+/// # Return:
+/// Result with either the decoded bytes or
+/// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+///
+/// fn main() {
+///     match lb64::decode("SGVsbG8=") {
+///         Ok(bytes) => println!("{:?}", bytes), // Prints the bytes of Hello
+///         Err(e) => println!("{}", e),
+///     }
+/// }
+/// ```
+pub fn decode(input: &str) -> Result<Vec<u8>, error::Base64Error> {
+    decode_config(input, config::STANDARD)
+}
+
+/// Decodes the provided Base64 &str into bytes using the provided config
+///
+/// # Return:
+/// Result with either the decoded bytes or
+/// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::config::URL_SAFE_NO_PADDING;
+///
+/// fn main() {
+///     match lb64::decode_config("SGVsbG8", URL_SAFE_NO_PADDING) {
+///         Ok(bytes) => println!("{:?}", bytes), // Prints the bytes of Hello
+///         Err(e) => println!("{}", e),
+///     }
+/// }
+/// ```
+pub fn decode_config(input: &str, conf: &config::Config) -> Result<Vec<u8>, error::Base64Error> {
+    Base64::new_from_string(input, conf)?.try_decode_to_bytes()
+}
+
+/// Lazily decodes characters using the provided config, each byte is yielded as soon as enough
+/// characters have been consumed so the characters don't have to be collected into a String
+/// first. Padding and whitespace the config ignores are skipped
+///
+/// # Return:
+/// Iterator of either each decoded byte or a
+/// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+/// of the first invalid character, after which the iterator ends
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::config::STANDARD;
+///
+/// fn main() {
+///     let decoded: Result<Vec<u8>, _> = lb64::decode_iter("SGk=".chars(), STANDARD).collect();
+///     println!("{:?}", decoded); // Prints Ok([72, 105])
+/// }
+/// ```
+pub fn decode_iter<'b, I: Iterator<Item = char> + 'b>(
+    chars: I,
+    conf: &'b config::Config<'b>,
+) -> impl Iterator<Item = Result<u8, error::Base64Error>> + 'b {
+    decode::DecodeIter::new(chars, conf)
+}
+
+/// Checks if every character of the &str is valid in the config, the same check as
+/// [Base64::new_from_string](struct.Base64.html#method.new_from_string) without creating a Base64
+/// number
+///
+/// # Parameters:
+/// s, the &str to check
+///
+/// conf, the config the &str should be valid in
+///
+/// # Return:
+/// true if the &str is valid Base64 false otherwise
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::config::URL_SAFE_NO_PADDING;
+///
+/// fn main() {
+///     println!("{}", lb64::is_valid("SGk-_", URL_SAFE_NO_PADDING)); // Prints true
+///     println!("{}", lb64::is_valid("SGk=", URL_SAFE_NO_PADDING)); // Prints false
+/// }
+/// ```
+pub fn is_valid(s: &str, conf: &config::Config) -> bool {
+    validate(s, conf).is_ok()
+}
+
+/// Checks if every character of the &str is valid in the config, the same check as
+/// [Base64::new_from_string](struct.Base64.html#method.new_from_string) without creating a Base64
+/// number
+///
+/// # Parameters:
+/// s, the &str to check
+///
+/// conf, the config the &str should be valid in
+///
+/// # Return:
+/// Result of either () or a
+/// [Base64Error::InvalidBase64CharacterError](error/enum.Base64Error.html#variant.InvalidBase64CharacterError)
+/// of the first invalid character
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::config::URL_SAFE_NO_PADDING;
+///
+/// fn main() {
+///     match lb64::validate("SGk=", URL_SAFE_NO_PADDING) {
+///         Ok(()) => println!("Valid"),
+///         Err(e) => println!("{}", e), // Prints "Invalid character '=' at index 3"
+///     }
+/// }
+/// ```
+pub fn validate(s: &str, conf: &config::Config) -> Result<(), error::Base64Error> {
+    match s
+        .chars()
+        .enumerate()
+        .find(|(_, ch)| !is_valid_base64(conf, *ch))
+    {
+        Some((index, character)) => {
+            Err(error::Base64Error::InvalidBase64CharacterError { character, index })
+        }
+        None => Ok(()),
+    }
+}
+
+/// The test vectors of [RFC 4648 section 10](https://tools.ietf.org/html/rfc4648#section-10),
+/// each input and its Base64 encoding with [STANDARD](config/constant.STANDARD.html). None of
+/// them use the characters for 62 and 63 so they're also the encodings with
+/// [URL_SAFE_PADDING](config/constant.URL_SAFE_PADDING.html)
+///
+/// # Return:
+/// the pairs of input bytes and encoded &str
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::Base64;
+/// use lb64::config::STANDARD;
+///
+/// fn main() {
+///     for (input, encoded) in lb64::rfc4648_vectors() {
+///         println!("{}", Base64::new_encode_bytes(input, STANDARD).as_str() == *encoded); // Prints true
+///     }
+/// }
+/// ```
+pub fn rfc4648_vectors() -> &'static [(&'static [u8], &'static str)] {
+    &[
+        (b"", ""),
+        (b"f", "Zg=="),
+        (b"fo", "Zm8="),
+        (b"foo", "Zm9v"),
+        (b"foob", "Zm9vYg=="),
+        (b"fooba", "Zm9vYmE="),
+        (b"foobar", "Zm9vYmFy"),
+    ]
+}
+
+/// Generates values from 0 to 63 and returns the character corresponding to it
+#[cfg(feature = "std")]
+fn generate_base64<R: Rng>(a: &[char], rng: &mut R) -> Option<char> {
+    decimal_to_base64_char(a, rng.gen_range(0, 64) as u128)
+}
+
+/// Gets a reference to the character from the config, the characters a Base64 number can hold
+/// that aren't in the config are line endings, spaces, and \0
+fn char_ref<'c>(conf: &'c config::Config, c: char) -> &'c char {
+    if let Some(i) = conf.get_character_set().iter().position(|x| *x == c) {
+        &conf.get_character_set()[i]
+    } else {
+        match c {
+            '\n' => &'\n',
+            '\r' => &'\r',
+            ' ' => &' ',
+            '\0' => &'\0',
+            _ => match conf.padding_ref() {
+                Some(pad) if *pad == c => pad,
+                _ => unreachable!("character isn't in the config"),
+            },
+        }
+    }
+}
+
+/// Checks if a character is a valid value in Base64, spaces and line endings are only valid if the
+/// config ignores whitespace
+/// Param: val, the character to check
+/// Return: true if it's value false otherwise
+fn is_valid_base64(conf: &config::Config, val: char) -> bool {
+    if val == '\n' || val == '\r' || val == ' ' {
+        // Whitespace is only valid when it's ignored
+        return conf.get_ignore_whitespace();
+    }
+    // \0 is let through for configs without padding, decoding reports it as an invalid character
+    conf.contains(val) || conf.is_padding(val) || (conf.get_padding().is_none() && val == '\0')
+}
+
+/// Convert decimal value to base64 by mod 64 to get the base64 place and then dividing
+/// by 64 to get the value
+/// Param: value, the value to convert
+/// Return String that is the Base64 value, 0 is the first character in the character set
+pub(crate) fn decimal_to_base64(conf: &config::Config, mut value: u128) -> String {
+    let mut v: Vec<char> = Vec::new();
+    while value > 0 {
+        let base64_val = value % 64;
+        value /= 64;
+        v.extend(decimal_to_base64_char(conf.get_character_set(), base64_val));
+    }
+    if v.is_empty() {
+        v.extend(decimal_to_base64_char(conf.get_character_set(), 0));
+    }
+    v.iter().rev().collect() // Reverse to get into proper order
+}
+
+/// Converts a decimal value to it's base 64 value
+/// Param: value, the value to convert
+/// Return: the character corresponding to the decimal in Base64, None if the value isn't in the
+/// character set instead of panicing
+pub(crate) fn decimal_to_base64_char(a: &[char], value: u128) -> Option<char> {
+    usize::try_from(value).ok().and_then(|i| a.get(i)).cloned()
+}
+
+/// Converts a char to it's corresponding u128 value in base64
+/// Param: value, char to convert
+/// Return: u128, the value of the char in base64
+pub(crate) fn base64_char_to_decimal(a: &[char], c: char) -> u128 {
+    for (i, val) in a.iter().enumerate() {
+        if c == *val {
+            return i as u128;
+        }
+    }
+    0 // Not Possible
+}
+
+impl<'a> Display for Base64<'a> {
+    /// Writes the characters of the Base64 number, the alternate form `{:#}` also writes a short
+    /// description of the config: the first and last characters of the character set, the
+    /// padding, and the line length
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::{MIME, URL_SAFE_NO_PADDING}; // Constant configs
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME);
+    ///     println!("{}", b64); // Prints SGk=
+    ///     println!("{:#}", b64); // Prints SGk= (alphabet=A../, pad='=', wrap=76)
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_NO_PADDING);
+    ///     println!("{:#}", b64); // Prints SGk (alphabet=A.._, pad=none, wrap=none)
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str(&self.value)?;
+        if f.alternate() {
+            let set = self.conf.get_character_set();
+            f.write_str(" (alphabet=")?;
+            if let (Some(first), Some(last)) = (set.first(), set.last()) {
+                write!(f, "{}..{}", first, last)?;
+            }
+            match self.conf.get_padding() {
+                Some(pad) => write!(f, ", pad='{}'", pad)?,
+                None => f.write_str(", pad=none")?,
+            }
+            match self.conf.get_line_length() {
+                Some(len) => write!(f, ", wrap={})", len)?,
+                None => f.write_str(", wrap=none)")?,
             }
         }
-        true
+        Ok(())
+    }
+}
+
+// Number of characters of the value shown by Debug before it's cut off
+const DEBUG_PREVIEW_LEN: usize = 32;
+
+impl<'a> fmt::Debug for Base64<'a> {
+    /// Writes the value, cut off after 32 characters, its length, and the padding of the config,
+    /// the whole config is left out to keep logs short
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant configs
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME);
+    ///     println!("{:?}", b64); // Prints Base64 { value: "SGk=", len: 4, padding: Some('=') }
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        let mut debug = f.debug_struct("Base64");
+        match self.value.char_indices().nth(DEBUG_PREVIEW_LEN) {
+            Some((end, _)) => debug.field("value", &format_args!("{:?}..", &self.value[..end])),
+            None => debug.field("value", &self.value),
+        };
+        debug
+            .field("len", &self.len())
+            .field("padding", &self.conf.get_padding())
+            .finish()
+    }
+}
+
+impl FromStr for Base64<'static> {
+    type Err = error::Base64Error;
+
+    /// Parses a Base64 number from a &str using the
+    /// [STANDARD](config/constant.STANDARD.html) config, padding is added so the length is
+    /// divisible by 4
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    ///
+    /// fn main() {
+    ///     match "SGVsbG8=".parse::<Base64>() {
+    ///         Ok(value) => println!("{}", value), // Prints "SGVsbG8="
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Base64::new_from_string(s, config::STANDARD)
+    }
+}
+
+impl<'a> TryFrom<(&'a str, &'a config::Config<'a>)> for Base64<'a> {
+    type Error = error::Base64Error;
+
+    /// Creates a Base64 number from a &str and a config, equivalent to
+    /// [new_from_string](struct.Base64.html#method.new_from_string). An empty &str creates an
+    /// empty Base64 number which decodes to 0
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    /// use std::convert::TryFrom;
+    ///
+    /// fn main() {
+    ///     match Base64::try_from(("SGk=", MIME)) {
+    ///         Ok(value) => println!("{}", value), // Prints "SGk="
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    fn try_from(value: (&'a str, &'a config::Config<'a>)) -> Result<Self, Self::Error> {
+        Base64::new_from_string(value.0, value.1)
+    }
+}
+
+impl<'a> TryFrom<(String, &'a config::Config<'a>)> for Base64<'a> {
+    type Error = error::Base64Error;
+
+    /// Creates a Base64 number from a String and a config, equivalent to
+    /// [new_from_string](struct.Base64.html#method.new_from_string). An empty String creates an
+    /// empty Base64 number which decodes to 0
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    /// use std::convert::TryFrom;
+    ///
+    /// fn main() {
+    ///     match Base64::try_from((String::from("CA"), URL_SAFE_NO_PADDING)) {
+    ///         Ok(value) => println!("{}", value), // Prints "CA"
+    ///         Err(e) => println!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    fn try_from(value: (String, &'a config::Config<'a>)) -> Result<Self, Self::Error> {
+        Base64::new_from_string(&value.0, value.1)
+    }
+}
+
+impl From<u128> for Base64<'static> {
+    /// Encodes an unsigned value with the [STANDARD](config/constant.STANDARD.html) config,
+    /// equivalent to [new_encode_unsigned](struct.Base64.html#method.new_encode_unsigned)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::from(128u128);
+    ///     println!("{}", b64); // Prints CA
+    /// }
+    /// ```
+    fn from(unsigned: u128) -> Self {
+        Base64::new_encode_unsigned(&unsigned, config::STANDARD)
+    }
+}
+
+impl<'a> From<(&'a [u8], &'a config::Config<'a>)> for Base64<'a> {
+    /// Encodes bytes with a config, equivalent to
+    /// [new_encode_bytes](struct.Base64.html#method.new_encode_bytes)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::from(("Hi".as_bytes(), MIME));
+    ///     println!("{}", b64); // Prints SGk=
+    /// }
+    /// ```
+    fn from(value: (&'a [u8], &'a config::Config<'a>)) -> Self {
+        Base64::new_encode_bytes(value.0, value.1)
+    }
+}
+
+impl<'a> PartialEq for Base64<'a> {
+    /// Two Base64 numbers are equal when they have the same characters and those characters have
+    /// the same decimal values in their configs, this is the same equality used by
+    /// [cmp](struct.Base64.html#method.cmp)
+    fn eq(&self, other: &Base64) -> bool {
+        self.value == other.value && self.digits().eq(other.digits())
+    }
+}
+
+/// Borrows the characters of the Base64 number with [as_str](struct.Base64.html#method.as_str)
+impl<'a> AsRef<str> for Base64<'a> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'b, 'a> IntoIterator for &'b Base64<'a> {
+    type Item = char;
+    type IntoIter = core::str::Chars<'b>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.value.chars()
+    }
+}
+
+impl<'a> Index<usize> for Base64<'a> {
+    type Output = char;
+
+    /// Indexes the character of the Base64 number, index 0 being the most significant.
+    ///
+    /// **Panics** if the index is out of bounds, see
+    /// [get](struct.Base64.html#method.get) for a version that doesn't panic
+    fn index(&self, i: usize) -> &char {
+        match self.get(i) {
+            Some(c) => char_ref(self.conf, c),
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                i
+            ),
+        }
+    }
+}
+
+impl<'a> Hash for Base64<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal numbers always have the same characters so only hash the value
+        self.value.hash(state);
     }
 }
 
 impl<'a> Ord for Base64<'a> {
+    /// Compares the values of the Base64 numbers, padding, newlines, and spaces are ignored and
+    /// each character is converted to it's decimal value using it's own config so numbers with
+    /// different configs are compared correctly. Numbers with the same value are then ordered by
+    /// their characters so Equal is only returned when they're equal with ==, which also requires
+    /// the same characters and the same decimal values
     fn cmp(&self, other: &Base64<'a>) -> Ordering {
-        if self.value.len() != other.value.len() {
+        let self_len = self.digits().count();
+        let other_len = other.digits().count();
+        if self_len != other_len {
             // Different lengths
-            return self.value.len().cmp(&other.value.len());
-        } else {
-            for i in 0..self.value.len() {
-                if self.value[i] != '\n'
-                    && other.value[i] != '\n'
-                    && self.value[i] != other.value[i]
-                {
-                    // Convert each to their decimal value then cmp
-                    return base64_char_to_decimal(self.conf.get_character_set(), self.value[i])
-                        .cmp(&base64_char_to_decimal(
-                            other.conf.get_character_set(),
-                            other.value[i],
-                        ));
-                }
+            return self_len.cmp(&other_len);
+        }
+        for (s, o) in self.digits().zip(other.digits()) {
+            if s != o {
+                return s.cmp(&o);
             }
         }
-        Ordering::Equal
+        // Same value, break the tie the way equality and hashing compare
+        self.value.cmp(&other.value)
     }
 }
 
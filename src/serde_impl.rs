@@ -0,0 +1,39 @@
+use core::fmt::Formatter;
+
+use serde::de::{Deserialize, Deserializer, Error, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use super::{config, config::Config, Base64};
+
+/// Configuration used when deserializing a Base64 number
+const DESERIALIZE_CONFIG: &Config<'static> = config::STANDARD;
+
+impl<'a> Serialize for Base64<'a> {
+    /// Serializes the Base64 number as a string
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.value)
+    }
+}
+
+/// Visitor that creates a Base64 number from a &str
+struct Base64Visitor;
+
+impl<'de> Visitor<'de> for Base64Visitor {
+    type Value = Base64<'static>;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str("a Base64 string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Base64::new_from_string(v, DESERIALIZE_CONFIG).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Base64<'static> {
+    /// Deserializes a string into a Base64 number using the
+    /// [STANDARD](../config/constant.STANDARD.html) config
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Base64Visitor)
+    }
+}
@@ -0,0 +1,229 @@
+// The intrinsics and unaligned loads and stores require unsafe, every block is bounds checked
+// before it's loaded or stored
+#![allow(unsafe_code)]
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use super::config::Config;
+use super::decode::decode_char;
+use super::encode::{encode_group, push_wrapped_at};
+use super::error::Base64Error;
+
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// Encodes the bytes 12 at a time into 16 characters with SSSE3, the last bytes are encoded by
+/// [encode_group](../encode/fn.encode_group.html) so padding and line endings are exactly the
+/// same as the scalar path
+///
+/// # Return:
+/// None if the CPU doesn't support SSSE3 or the config has a character that isn't ASCII, then
+/// the scalar path is used instead
+pub(crate) fn encode(conf: &Config, s: &[u8]) -> Option<String> {
+    let luts = encode_luts(conf)?;
+    // Each block reads 16 bytes but only encodes the first 12
+    let blocks = if s.len() >= 16 { (s.len() - 4) / 12 } else { 0 };
+    let mut chars: Vec<u8> = Vec::with_capacity(blocks * 16);
+    encode_blocks(s, blocks, &luts, &mut chars)?;
+    let mut b64_str: String = String::with_capacity(super::checked_encoded_len(s.len(), conf)?);
+    let mut count = 0;
+    for c in chars {
+        push_wrapped_at(
+            conf.get_line_length(),
+            conf.get_line_ending(),
+            &mut b64_str,
+            char::from(c),
+            &mut count,
+        );
+    }
+    for group in s[blocks * 12..].chunks(3) {
+        encode_group(conf, group, &mut b64_str, &mut count);
+    }
+    Some(b64_str)
+}
+
+/// Decodes the characters 16 at a time into 12 bytes with SSSE3, any block that has a character
+/// that isn't a digit, such as padding, a line ending, or an invalid character, is decoded one
+/// character at a time by [decode_char](../decode/fn.decode_char.html) so errors are exactly the
+/// same as the scalar path
+///
+/// # Return:
+/// None if the CPU doesn't support SSSE3 or the config has a character that isn't ASCII, then
+/// the scalar path is used instead
+pub(crate) fn decode(conf: &Config, s: &str) -> Option<Result<Vec<u8>, Base64Error>> {
+    if !is_ascii_config(conf) || !has_ssse3() {
+        return None;
+    }
+    let bytes = s.as_bytes();
+    let mut v: Vec<u8> = Vec::with_capacity(s.len() / 4 * 3 + 3);
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut pos = 0;
+    while pos < bytes.len() {
+        // Blocks can only be decoded when no bits are left over from the previous characters
+        if bits == 0 && pos + 16 <= bytes.len() && decode_block(conf, &bytes[pos..pos + 16], &mut v)
+        {
+            pos += 16;
+            continue;
+        }
+        // Every character before pos is ASCII, otherwise decoding would've failed, so the byte
+        // position is also the character index
+        let c = s.get(pos..)?.chars().next()?;
+        if let Err(e) = decode_char(conf, c, pos, &mut buffer, &mut bits, &mut v) {
+            return Some(Err(e));
+        }
+        pos += c.len_utf8();
+    }
+    Some(Ok(v))
+}
+
+/// Checks if every character of the config, including padding, is ASCII so each one is a single
+/// byte
+fn is_ascii_config(conf: &Config) -> bool {
+    conf.get_character_set().iter().all(char::is_ascii)
+        && conf.get_padding().filter(|pad| !pad.is_ascii()).is_none()
+}
+
+/// Splits the character set into 4 tables of 16 characters, the character of a digit is found in
+/// the table of the digit's 2 most significant bits at the digit's 4 least significant bits
+fn encode_luts(conf: &Config) -> Option<[[u8; 16]; 4]> {
+    if !is_ascii_config(conf) || !has_ssse3() {
+        return None;
+    }
+    let mut luts = [[0u8; 16]; 4];
+    for (i, c) in conf.get_character_set().iter().take(64).enumerate() {
+        luts[i / 16][i % 16] = u8::try_from(*c).ok()?;
+    }
+    Some(luts)
+}
+
+#[cfg(target_arch = "x86_64")]
+fn has_ssse3() -> bool {
+    std::is_x86_feature_detected!("ssse3")
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn has_ssse3() -> bool {
+    false
+}
+
+/// Encodes blocks of 12 bytes into 16 characters each, pushing them onto out
+#[cfg(target_arch = "x86_64")]
+fn encode_blocks(s: &[u8], blocks: usize, luts: &[[u8; 16]; 4], out: &mut Vec<u8>) -> Option<()> {
+    if !has_ssse3() || (blocks > 0 && blocks * 12 + 4 > s.len()) {
+        return None;
+    }
+    // SSSE3 is supported and every block reads 16 bytes within s
+    unsafe { encode_blocks_ssse3(s, blocks, luts, out) };
+    Some(())
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn encode_blocks(
+    _s: &[u8],
+    _blocks: usize,
+    _luts: &[[u8; 16]; 4],
+    _out: &mut Vec<u8>,
+) -> Option<()> {
+    None
+}
+
+/// Decodes a block of 16 characters into 12 bytes pushing them onto v
+///
+/// # Return:
+/// true if every character was a digit and the block was decoded, false otherwise and nothing is
+/// pushed
+#[cfg(target_arch = "x86_64")]
+fn decode_block(conf: &Config, block: &[u8], v: &mut Vec<u8>) -> bool {
+    if block.len() != 16 || !has_ssse3() {
+        return false;
+    }
+    // SSSE3 is supported and the block is 16 bytes
+    unsafe { decode_block_ssse3(conf.ascii_digits(), block, v) }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn decode_block(_conf: &Config, _block: &[u8], _v: &mut Vec<u8>) -> bool {
+    false
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_blocks_ssse3(s: &[u8], blocks: usize, luts: &[[u8; 16]; 4], out: &mut Vec<u8>) {
+    // Places the 3 bytes of each group in the order the shifts below expect
+    let shuffle = _mm_setr_epi8(1, 0, 2, 1, 4, 3, 5, 4, 7, 6, 8, 7, 10, 9, 11, 10);
+    let low_nibble = _mm_set1_epi8(0x0F);
+    let mut tables = [_mm_setzero_si128(); 4];
+    for (table, lut) in tables.iter_mut().zip(luts.iter()) {
+        *table = _mm_loadu_si128(lut.as_ptr() as *const __m128i);
+    }
+    for block in 0..blocks {
+        let input = _mm_loadu_si128(s.as_ptr().add(block * 12) as *const __m128i);
+        let input = _mm_shuffle_epi8(input, shuffle);
+        // Moves each 6 bits into its own byte
+        let first = _mm_mulhi_epu16(
+            _mm_and_si128(input, _mm_set1_epi32(0x0FC0_FC00)),
+            _mm_set1_epi32(0x0400_0040),
+        );
+        let second = _mm_mullo_epi16(
+            _mm_and_si128(input, _mm_set1_epi32(0x003F_03F0)),
+            _mm_set1_epi32(0x0100_0010),
+        );
+        let digits = _mm_or_si128(first, second);
+        // Looks up the character of each digit in the table of its 2 most significant bits
+        let index = _mm_and_si128(digits, low_nibble);
+        let table_of = _mm_and_si128(_mm_srli_epi16(digits, 4), low_nibble);
+        let mut chars = _mm_setzero_si128();
+        for (i, table) in tables.iter().enumerate() {
+            let selected = _mm_cmpeq_epi8(table_of, _mm_set1_epi8(i as i8));
+            chars = _mm_or_si128(
+                chars,
+                _mm_and_si128(selected, _mm_shuffle_epi8(*table, index)),
+            );
+        }
+        let mut encoded = [0u8; 16];
+        _mm_storeu_si128(encoded.as_mut_ptr() as *mut __m128i, chars);
+        out.extend_from_slice(&encoded);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "ssse3")]
+unsafe fn decode_block_ssse3(digits: &[u8; 128], block: &[u8], v: &mut Vec<u8>) -> bool {
+    let input = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+    if _mm_movemask_epi8(input) != 0 {
+        // A character isn't ASCII
+        return false;
+    }
+    // Looks up the digit of each character in the table of its 3 most significant bits
+    let low_nibble = _mm_set1_epi8(0x0F);
+    let index = _mm_and_si128(input, low_nibble);
+    let table_of = _mm_and_si128(_mm_srli_epi16(input, 4), low_nibble);
+    let mut values = _mm_setzero_si128();
+    for (i, table) in digits.chunks(16).enumerate() {
+        let table = _mm_loadu_si128(table.as_ptr() as *const __m128i);
+        let selected = _mm_cmpeq_epi8(table_of, _mm_set1_epi8(i as i8));
+        values = _mm_or_si128(
+            values,
+            _mm_and_si128(selected, _mm_shuffle_epi8(table, index)),
+        );
+    }
+    let invalid = _mm_cmpeq_epi8(values, _mm_set1_epi8(super::config::NOT_A_DIGIT as i8));
+    if _mm_movemask_epi8(invalid) != 0 {
+        // A character isn't a digit
+        return false;
+    }
+    // Joins every 4 digits into 3 bytes
+    let merged = _mm_maddubs_epi16(values, _mm_set1_epi32(0x0140_0140));
+    let merged = _mm_madd_epi16(merged, _mm_set1_epi32(0x0001_1000));
+    let packed = _mm_shuffle_epi8(
+        merged,
+        _mm_setr_epi8(2, 1, 0, 6, 5, 4, 10, 9, 8, 14, 13, 12, -1, -1, -1, -1),
+    );
+    let mut decoded = [0u8; 16];
+    _mm_storeu_si128(decoded.as_mut_ptr() as *mut __m128i, packed);
+    v.extend_from_slice(&decoded[..12]);
+    true
+}
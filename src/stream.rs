@@ -0,0 +1,249 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use super::config::Config;
+use super::decode::decode_char;
+use super::encode::encode_group;
+
+// Multiple of 3 so every read except the last encodes without leftover bytes
+const ENCODE_BUFFER_LEN: usize = 3 * 1024;
+// Multiple of 4 so every read except the last is a whole number of groups of digits
+const DECODE_BUFFER_LEN: usize = 4 * 1024;
+
+/// Encoder that wraps a writer and encodes all bytes written to it into Base64 before writing
+/// them to the inner writer, the output is the same as
+/// [Base64::new_encode_bytes](../struct.Base64.html#method.new_encode_bytes) of all the bytes
+/// written.
+///
+/// Bytes are encoded in groups of 3, so up to 2 bytes are buffered between writes. The final
+/// group and padding are only written by [finish](struct.Encoder.html#method.finish), which must
+/// be called once all bytes have been written.
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::{config::MIME, stream::Encoder};
+/// use std::io::Write;
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut encoder = Encoder::new(Vec::new(), MIME);
+///     encoder.write_all(b"Hello, ")?;
+///     encoder.write_all(b"World")?;
+///     let encoded = encoder.finish()?;
+///     println!("{}", String::from_utf8(encoded).unwrap()); // Prints SGVsbG8sIFdvcmxk
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Encoder<'a, W: Write> {
+    writer: W,
+    conf: &'a Config<'a>,
+    buffer: Vec<u8>,
+    count: usize,
+}
+
+impl<'a, W: Write> Encoder<'a, W> {
+    /// Creates a new Encoder that writes the Base64 encoding of everything written to it to
+    /// writer
+    ///
+    /// # Parameters:
+    /// The writer to write the encoded Base64 to
+    ///
+    /// The configuration struct
+    pub fn new(writer: W, conf: &'a Config<'a>) -> Self {
+        Encoder {
+            writer,
+            conf,
+            buffer: Vec::with_capacity(3),
+            count: 0,
+        }
+    }
+
+    /// Encodes the remaining buffered bytes along with padding, flushes the inner writer, and
+    /// returns it
+    ///
+    /// # Returns:
+    /// The inner writer or the io::Error that occurred when writing to it
+    pub fn finish(mut self) -> Result<W> {
+        if !self.buffer.is_empty() {
+            let mut encoded = String::new();
+            encode_group(self.conf, &self.buffer, &mut encoded, &mut self.count);
+            self.writer.write_all(encoded.as_bytes())?;
+            self.buffer.clear();
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<'a, W: Write> Write for Encoder<'a, W> {
+    /// Encodes every complete group of 3 bytes and writes them to the inner writer, the rest are
+    /// buffered until the next write or [finish](struct.Encoder.html#method.finish)
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let mut encoded = String::new();
+        let mut rest = buf;
+        // Complete the group buffered from the previous write
+        while !self.buffer.is_empty() && self.buffer.len() < 3 && !rest.is_empty() {
+            self.buffer.push(rest[0]);
+            rest = &rest[1..];
+        }
+        if self.buffer.len() == 3 {
+            encode_group(self.conf, &self.buffer, &mut encoded, &mut self.count);
+            self.buffer.clear();
+        }
+        let complete = rest.len() - rest.len() % 3;
+        for group in rest[..complete].chunks(3) {
+            encode_group(self.conf, group, &mut encoded, &mut self.count);
+        }
+        self.buffer.extend_from_slice(&rest[complete..]);
+        self.writer.write_all(encoded.as_bytes())?;
+        Ok(buf.len())
+    }
+
+    /// Flushes the inner writer, buffered bytes that don't make a complete group of 3 aren't
+    /// written
+    fn flush(&mut self) -> Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Encodes everything read from src into Base64 and writes it to dst, the output is the same as
+/// [Base64::new_encode_bytes](../struct.Base64.html#method.new_encode_bytes) of all the bytes
+/// read. src is read until the end in blocks of a multiple of 3 bytes
+///
+/// # Parameters:
+/// The reader to encode
+///
+/// The writer to write the encoded Base64 to
+///
+/// The configuration struct
+///
+/// # Returns:
+/// The number of bytes read from src or the io::Error that occurred when reading or writing
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::{config::MIME, stream::encode_reader};
+/// use std::io::Cursor;
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut encoded = Vec::new();
+///     let read = encode_reader(&mut Cursor::new(b"Hello, World"), &mut encoded, MIME)?;
+///     println!("{}", read); // Prints 12
+///     println!("{}", String::from_utf8(encoded).unwrap()); // Prints SGVsbG8sIFdvcmxk
+///     Ok(())
+/// }
+/// ```
+pub fn encode_reader<R: Read, W: Write>(src: &mut R, dst: &mut W, conf: &Config) -> Result<u64> {
+    let mut buffer = [0u8; ENCODE_BUFFER_LEN];
+    let mut encoded = String::new();
+    let mut count = 0;
+    let mut total: u64 = 0;
+    loop {
+        let len = read_full(src, &mut buffer)?;
+        if len == 0 {
+            break;
+        }
+        total += len as u64;
+        encoded.clear();
+        for group in buffer[..len].chunks(3) {
+            encode_group(conf, group, &mut encoded, &mut count);
+        }
+        dst.write_all(encoded.as_bytes())?;
+        if len < buffer.len() {
+            // Only the last read can leave the buffer partially filled
+            break;
+        }
+    }
+    dst.flush()?;
+    Ok(total)
+}
+
+/// Decodes the Base64 read from src and writes the bytes to dst, the output is the same as
+/// [decode_to_bytes](../struct.Base64.html#method.decode_to_bytes) of everything read. src is
+/// read until the end in blocks of a multiple of 4 bytes, padding is skipped and line endings and
+/// spaces are skipped if the config ignores whitespace
+///
+/// # Parameters:
+/// The reader of Base64 to decode
+///
+/// The writer to write the decoded bytes to
+///
+/// The configuration struct
+///
+/// # Returns:
+/// The number of bytes read from src or the io::Error that occurred when reading or writing, an
+/// error of kind InvalidData if what was read isn't UTF-8 or has a character that isn't in the
+/// character set
+///
+/// # Example:
+/// ```
+/// extern crate lb64;
+/// use lb64::{config::MIME, stream::decode_reader};
+/// use std::io::Cursor;
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut decoded = Vec::new();
+///     let read = decode_reader(&mut Cursor::new("SGVsbG8sIFdvcmxk"), &mut decoded, MIME)?;
+///     println!("{}", read); // Prints 16
+///     println!("{}", String::from_utf8(decoded).unwrap()); // Prints Hello, World
+///     Ok(())
+/// }
+/// ```
+pub fn decode_reader<R: Read, W: Write>(src: &mut R, dst: &mut W, conf: &Config) -> Result<u64> {
+    let mut buffer = [0u8; DECODE_BUFFER_LEN];
+    // Bytes at the end of the last read that are the start of a character that isn't complete
+    let mut start = 0;
+    let mut decoded: Vec<u8> = Vec::with_capacity(DECODE_BUFFER_LEN / 4 * 3);
+    let mut bits_buffer: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut index = 0;
+    let mut total: u64 = 0;
+    loop {
+        let len = read_full(src, &mut buffer[start..])?;
+        total += len as u64;
+        let end = start + len;
+        let (chars, rest) = match core::str::from_utf8(&buffer[..end]) {
+            Ok(chars) => (chars, end),
+            // The last character continues in the next read
+            Err(e) if e.error_len().is_none() && len > 0 => {
+                let valid = e.valid_up_to();
+                (core::str::from_utf8(&buffer[..valid]).unwrap_or(""), valid)
+            }
+            Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
+        };
+        decoded.clear();
+        for c in chars.chars() {
+            decode_char(conf, c, index, &mut bits_buffer, &mut bits, &mut decoded)
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            index += 1;
+        }
+        dst.write_all(&decoded)?;
+        if len == 0 {
+            break;
+        }
+        buffer.copy_within(rest..end, 0);
+        start = end - rest;
+    }
+    dst.flush()?;
+    Ok(total)
+}
+
+/// Reads from src until buf is full or the end is reached, retrying interrupted reads
+///
+/// # Returns:
+/// The number of bytes read, less than the length of buf only at the end
+fn read_full<R: Read>(src: &mut R, buf: &mut [u8]) -> Result<usize> {
+    let mut len = 0;
+    while len < buf.len() {
+        match src.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(len)
+}
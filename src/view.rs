@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use super::decode::{decode_bytes, decode_unsigned};
+use super::error::Base64Error;
+use super::{Base64, Base64Ref};
+
+impl<'a> Base64<'a> {
+    /// Borrows the Base64 number as a [Base64Ref](struct.Base64Ref.html) which only reads it, so
+    /// it can be passed around without being cloned
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING); // Sets b64 to CA
+    ///     let view = b64.as_ref_view();
+    ///     println!("{}", view); // Prints CA
+    ///     println!("{:?}", view.decode_to_unsigned()); // Prints Ok(128)
+    /// }
+    /// ```
+    pub fn as_ref_view(&self) -> Base64Ref<'_> {
+        Base64Ref {
+            value: &self.value,
+            conf: self.conf,
+        }
+    }
+}
+
+impl<'a> Base64Ref<'a> {
+    /// Gets the length of the Base64 number like [Base64::len](struct.Base64.html#method.len)
+    ///
+    /// # Return:
+    /// Return usize of Base64 number
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME); // Sets b64 to SGk=
+    ///     println!("{}", b64.as_ref_view().len()); // Prints 4
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    /// Checks if the Base64 number has no characters like
+    /// [Base64::is_empty](struct.Base64.html#method.is_empty)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     println!("{}", Base64::empty(MIME).as_ref_view().is_empty()); // Prints true
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Decode the Base64 value to bytes like
+    /// [Base64::decode_to_bytes](struct.Base64.html#method.decode_to_bytes)
+    ///
+    /// # Return:
+    /// The vector of u8 corresponding to the data that was encoded into base64, empty if the
+    /// value isn't valid
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::MIME; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME);
+    ///     println!("{:?}", b64.as_ref_view().decode_to_bytes()); // Prints [72, 105]
+    /// }
+    /// ```
+    pub fn decode_to_bytes(&self) -> Vec<u8> {
+        decode_bytes(self.conf, self.value).unwrap_or_default()
+    }
+
+    /// Decode the Base64 number to an unsigned value like
+    /// [Base64::decode_to_unsigned](struct.Base64.html#method.decode_to_unsigned)
+    ///
+    /// # Return:
+    /// Result of either the unsigned value or a
+    /// [Base64Error::OverflowError](error/enum.Base64Error.html#variant.OverflowError)
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate lb64; // Import/Include crate
+    /// use lb64::{Base64}; // Base64
+    /// use lb64::config::URL_SAFE_NO_PADDING; // Constant config
+    ///
+    /// fn main() {
+    ///     let b64 = Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING);
+    ///     println!("{:?}", b64.as_ref_view().decode_to_unsigned()); // Prints Ok(128)
+    /// }
+    /// ```
+    pub fn decode_to_unsigned(&self) -> Result<u128, Base64Error> {
+        decode_unsigned(self.conf, self.value)
+    }
+}
+
+impl<'a> Display for Base64Ref<'a> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str(self.value)
+    }
+}
@@ -1,13 +1,21 @@
 extern crate lb64;
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::needless_borrow)] // Kept as originally written
 mod tests {
     #[allow(unused_imports)] // Allow imports of everything
-    use lb64::config::{Config, IMAP, MIME, STANDARD, URL_SAFE_NO_PADDING, URL_SAFE_PADDING};
+    use lb64::config::{
+        Config, LineEnding, OwnedConfig, BCRYPT, CRYPT, IMAP, MIME, PEM, STANDARD,
+        URL_SAFE_NO_PADDING, URL_SAFE_PADDING, XML_NMTOKEN, Y64,
+    };
     #[allow(unused_imports)] // Allow imports of everything
     use lb64::error::{Base64Error, ConfigError};
+    use lb64::stream::{decode_reader, encode_reader, Encoder};
     #[allow(unused_imports)] // Allow imports of everything
-    use lb64::Base64;
+    use lb64::{Base64, ByteOrder};
+    use std::collections::HashSet;
+    use std::convert::TryFrom;
+    use std::io::Write;
 
     #[test]
     fn create_from_10() {
@@ -236,7 +244,7 @@ mod tests {
     #[test]
     fn set_config() {
         let mut b64 = Base64::new_encode_unsigned(&62, STANDARD);
-        assert_eq!(b64.to_string(), "+===");
+        assert_eq!(b64.to_string(), "+");
         b64.set_config(URL_SAFE_NO_PADDING);
         assert_eq!(b64.to_string(), "-");
     }
@@ -376,7 +384,10 @@ mod tests {
     fn base64_encode_paragraph() {
         let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Dictum fusce ut placerat orci nulla pellentesque. Consequat mauris nunc congue nisi vitae suscipit tellus mauris a.";
         let b64: Base64 = Base64::new_encode_bytes(s.as_bytes(), MIME);
-        assert_eq!("TG9yZW0gaXBzdW0gZG9sb3Igc2l0IGFtZXQsIGNvbnNlY3RldHVyIGFkaXBpc2NpbmcgZWxpdCwg\nc2VkIGRvIGVpdXNtb2QgdGVtcG9yIGluY2lkaWR1bnQgdXQgbGFib3JlIGV0IGRvbG9yZSBtYWduY\nSBhbGlxdWEuIERpY3R1bSBmdXNjZSB1dCBwbGFjZXJhdCBvcmNpIG51bGxhIHBlbGxlbnRlc3F1ZS\n4gQ29uc2VxdWF0IG1hdXJpcyBudW5jIGNvbmd1ZSBuaXNpIHZpdGFlIHN1c2NpcGl0IHRlbGx1cyB\ntYXVyaXMgYS4=", b64.to_string());
+        assert_eq!("TG9yZW0gaXBzdW0gZG9sb3Igc2l0IGFtZXQsIGNvbnNlY3RldHVyIGFkaXBpc2NpbmcgZWxpdCwg\r\nc2VkIGRvIGVpdXNtb2QgdGVtcG9yIGluY2lkaWR1bnQgdXQgbGFib3JlIGV0IGRvbG9yZSBtYWdu\r\nYSBhbGlxdWEuIERpY3R1bSBmdXNjZSB1dCBwbGFjZXJhdCBvcmNpIG51bGxhIHBlbGxlbnRlc3F1\r\nZS4gQ29uc2VxdWF0IG1hdXJpcyBudW5jIGNvbmd1ZSBuaXNpIHZpdGFlIHN1c2NpcGl0IHRlbGx1\r\ncyBtYXVyaXMgYS4=", b64.to_string());
+        for line in b64.as_str().split("\r\n") {
+            assert!(line.len() <= 76);
+        }
     }
 
     #[test]
@@ -425,4 +436,2286 @@ mod tests {
         let b64: Base64 = Base64::new_encode_bytes(s.as_bytes(), MIME);
         assert_eq!("Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Dictum fusce ut placerat orci nulla pellentesque. Consequat mauris nunc congue nisi vitae suscipit tellus mauris a.", String::from_utf8(b64.decode_to_bytes()).unwrap());
     }
+
+    #[test]
+    fn parse_from_str() {
+        let b64: Base64 = "SGVsbG8=".parse().unwrap();
+        assert_eq!(b64.to_string(), "SGVsbG8=");
+        assert_eq!("Hello", String::from_utf8(b64.decode_to_bytes()).unwrap());
+    }
+
+    #[test]
+    fn parse_from_str_adds_padding() {
+        let b64: Base64 = "SGk".parse().unwrap();
+        assert_eq!(b64.to_string(), "SGk=");
+    }
+
+    #[test]
+    fn parse_from_str_invalid_character() {
+        match "^_^".parse::<Base64>() {
+            Ok(val) => panic!("{} shouldn't be valid", val),
+            Err(e) => assert_eq!(
+                e,
+                Base64Error::InvalidBase64CharacterError {
+                    character: '^',
+                    index: 0
+                }
+            ),
+        }
+    }
+
+    #[test]
+    fn try_from_str_with_padding() {
+        let b64 = Base64::try_from(("SGk=", MIME)).unwrap();
+        assert_eq!(b64.to_string(), "SGk=");
+        assert_eq!("Hi", String::from_utf8(b64.decode_to_bytes()).unwrap());
+    }
+
+    #[test]
+    fn try_from_string() {
+        let b64 = Base64::try_from((String::from("CA"), URL_SAFE_NO_PADDING)).unwrap();
+        assert_eq!(b64.decode_to_unsigned().unwrap(), 128);
+    }
+
+    #[test]
+    fn try_from_empty() {
+        let b64 = Base64::try_from(("", URL_SAFE_PADDING)).unwrap();
+        assert_eq!(b64.len(), 0);
+        assert_eq!(b64.decode_to_unsigned().unwrap(), 0);
+        let b64 = Base64::try_from((String::new(), URL_SAFE_NO_PADDING)).unwrap();
+        assert_eq!(b64.len(), 0);
+    }
+
+    #[test]
+    fn try_from_invalid_character() {
+        match Base64::try_from(("SGk=", URL_SAFE_NO_PADDING)) {
+            Ok(val) => panic!("{} shouldn't be valid", val),
+            Err(e) => assert_eq!(
+                e,
+                Base64Error::InvalidBase64CharacterError {
+                    character: '=',
+                    index: 3
+                }
+            ),
+        }
+    }
+
+    #[test]
+    fn hash_set_membership() {
+        let mut set = HashSet::new();
+        for i in 0..10 {
+            set.insert(Base64::new_encode_unsigned(&(i * 100), URL_SAFE_NO_PADDING));
+        }
+        assert_eq!(set.len(), 10);
+        assert!(set.contains(&Base64::new_encode_unsigned(&300, URL_SAFE_NO_PADDING)));
+        assert!(!set.contains(&Base64::new_encode_unsigned(&301, URL_SAFE_NO_PADDING)));
+        set.insert(Base64::new_encode_unsigned(&900, URL_SAFE_NO_PADDING));
+        assert_eq!(set.len(), 10);
+    }
+
+    #[test]
+    fn chars_includes_padding() {
+        let b64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_PADDING);
+        assert_eq!(b64.chars().collect::<String>(), "SGk=");
+        let mut v: Vec<char> = Vec::new();
+        for c in &b64 {
+            v.push(c);
+        }
+        assert_eq!(v, vec!['S', 'G', 'k', '=']);
+    }
+
+    #[test]
+    fn digits_skip_padding() {
+        let b64 = Base64::new_encode_unsigned(&65538, URL_SAFE_PADDING);
+        assert_eq!(b64.digits().collect::<Vec<u128>>(), vec![16, 0, 2]);
+    }
+
+    #[test]
+    fn index_most_significant() {
+        let b64 = Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING);
+        assert_eq!(b64[0], 'C');
+        assert_eq!(b64[1], 'A');
+    }
+
+    #[test]
+    fn get_past_end() {
+        let b64 = Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING);
+        assert_eq!(b64.get(1), Some('A'));
+        assert_eq!(b64.get(2), None);
+    }
+
+    #[test]
+    fn try_decode_to_bytes_hello() {
+        let b64 = Base64::new_encode_bytes("Hello".as_bytes(), MIME);
+        assert_eq!(b64.try_decode_to_bytes().unwrap(), "Hello".as_bytes());
+    }
+
+    #[test]
+    fn try_decode_to_bytes_foreign_character() {
+        let mut b64 = Base64::default();
+        b64.set_config(URL_SAFE_NO_PADDING);
+        assert!(b64.set_from_string("SG\0k"));
+        assert_eq!(
+            b64.try_decode_to_bytes(),
+            Err(Base64Error::InvalidBase64CharacterError {
+                character: '\0',
+                index: 2
+            })
+        );
+        assert_eq!(b64.decode_to_bytes(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn extend_128_4() {
+        let mut x: Base64 = Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING);
+        x.expand_to(4);
+        assert_eq!(x.to_string(), "AACA");
+        assert_eq!(x.decode_to_unsigned().unwrap(), 128);
+    }
+
+    #[test]
+    fn extend_65538_5() {
+        let mut x: Base64 = Base64::new_encode_unsigned(&65538, URL_SAFE_NO_PADDING);
+        x.expand_to(5);
+        assert_eq!(x.to_string(), "AAQAC");
+    }
+
+    #[test]
+    fn config_set_padding() {
+        let character_set = &[
+            'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+            'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
+            'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+            'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '/',
+        ];
+        let mut conf = Config::new(character_set, None, None).unwrap();
+        assert_eq!(conf.set_padding(Some('=')), Ok(()));
+        assert_eq!(conf.get_padding(), Some('='));
+    }
+
+    #[test]
+    fn config_set_padding_err_unrepresentable() {
+        let character_set = &[
+            'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+            'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
+            'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+            'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '/',
+        ];
+        let mut conf = Config::new(character_set, None, None).unwrap();
+        assert_eq!(
+            conf.set_padding(Some('\0')),
+            Err(ConfigError::PaddingUnrepresentableCharacter)
+        );
+        assert_eq!(conf.get_padding(), None);
+    }
+
+    #[test]
+    fn cmp_different_configs() {
+        let standard = Base64::new_encode_unsigned(&62, STANDARD);
+        let url_safe = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING);
+        assert!(url_safe > standard);
+        assert!(standard < url_safe);
+    }
+
+    #[test]
+    fn cmp_ignores_padding() {
+        let padded = Base64::new_encode_unsigned(&128, URL_SAFE_PADDING);
+        let not_padded = Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING);
+        assert_eq!(padded.cmp(&not_padded), std::cmp::Ordering::Equal);
+        let smaller = Base64::new_encode_unsigned(&127, URL_SAFE_NO_PADDING);
+        assert!(smaller < padded);
+    }
+
+    #[test]
+    fn owned_config_from_alphabet_str() {
+        let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+        let owned = OwnedConfig::from_alphabet_str(alphabet, None, None).unwrap();
+        let conf = owned.as_config();
+        assert_eq!(&conf, URL_SAFE_NO_PADDING);
+        let b64 = Base64::new_encode_unsigned(&63, &conf);
+        assert_eq!(b64.to_string(), "_");
+    }
+
+    #[test]
+    fn owned_config_errors() {
+        assert_eq!(
+            OwnedConfig::from_alphabet_str("ABC", None, None),
+            Err(ConfigError::CharacterSetLengthError)
+        );
+        let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789--";
+        assert_eq!(
+            OwnedConfig::from_alphabet_str(alphabet, None, None),
+            Err(ConfigError::DuplicateCharacterError)
+        );
+        let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+        assert_eq!(
+            OwnedConfig::from_alphabet_str(alphabet, Some('_'), None),
+            Err(ConfigError::NotUniquePaddingError)
+        );
+    }
+
+    #[test]
+    fn config_builder() {
+        let conf = Config::builder()
+            .alphabet(MIME.get_character_set())
+            .padding('=')
+            .line_length(76)
+            .line_ending(LineEnding::CrLf)
+            .build()
+            .unwrap();
+        assert_eq!(&conf, MIME);
+        let conf = Config::builder()
+            .alphabet(URL_SAFE_NO_PADDING.get_character_set())
+            .build()
+            .unwrap();
+        assert_eq!(&conf, URL_SAFE_NO_PADDING);
+    }
+
+    #[test]
+    fn config_builder_errors() {
+        assert_eq!(
+            Config::builder().build(),
+            Err(ConfigError::CharacterSetLengthError)
+        );
+        assert_eq!(
+            Config::builder()
+                .alphabet(STANDARD.get_character_set())
+                .padding('+')
+                .build(),
+            Err(ConfigError::NotUniquePaddingError)
+        );
+    }
+
+    #[test]
+    fn decode_to_fixed_width() {
+        let b64 = Base64::new_encode_unsigned(&200, URL_SAFE_NO_PADDING);
+        assert_eq!(b64.decode_to_u8(), Ok(200));
+        assert_eq!(b64.decode_to_u16(), Ok(200));
+        assert_eq!(b64.decode_to_u32(), Ok(200));
+        assert_eq!(b64.decode_to_u64(), Ok(200));
+    }
+
+    #[test]
+    fn decode_to_u32_overflow() {
+        let b64 = Base64::new_encode_unsigned(&(u128::from(u32::MAX) + 1), URL_SAFE_NO_PADDING);
+        assert_eq!(b64.decode_to_u32(), Err(Base64Error::OverflowError));
+        assert_eq!(b64.decode_to_u64(), Ok(u64::from(u32::MAX) + 1));
+        let b64 = Base64::new_encode_unsigned(&u128::from(u32::MAX), URL_SAFE_NO_PADDING);
+        assert_eq!(b64.decode_to_u32(), Ok(u32::MAX));
+    }
+
+    #[test]
+    fn decode_to_u8_overflow() {
+        let b64 = Base64::new_encode_unsigned(&256, URL_SAFE_NO_PADDING);
+        assert_eq!(b64.decode_to_u8(), Err(Base64Error::OverflowError));
+    }
+
+    #[test]
+    fn signed_round_trip() {
+        for value in &[0, -1, 1, -64, 64, i128::MIN, i128::MAX] {
+            let b64 = Base64::new_encode_signed(value, URL_SAFE_NO_PADDING);
+            assert_eq!(b64.decode_to_signed(), Ok(*value));
+        }
+    }
+
+    #[test]
+    fn signed_zig_zag() {
+        assert_eq!(
+            Base64::new_encode_signed(&0, URL_SAFE_NO_PADDING).to_string(),
+            "A"
+        );
+        assert_eq!(
+            Base64::new_encode_signed(&-1, URL_SAFE_NO_PADDING).to_string(),
+            "B"
+        );
+        assert_eq!(
+            Base64::new_encode_signed(&1, URL_SAFE_NO_PADDING).to_string(),
+            "C"
+        );
+    }
+
+    #[test]
+    fn decode_to_signed_overflow() {
+        let b64 = Base64::new_from_string(&"_".repeat(30), URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(b64.decode_to_signed(), Err(Base64Error::OverflowError));
+    }
+
+    #[test]
+    fn bignum_matches_unsigned() {
+        let b64 = Base64::new_encode_bignum(&[1, 0, 2], URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "QAC");
+        assert_eq!(
+            b64,
+            Base64::new_encode_unsigned(&65538, URL_SAFE_NO_PADDING)
+        );
+        let value: u128 = 20769187000000000000000000000000000;
+        let b64 = Base64::new_encode_bignum(&value.to_be_bytes(), URL_SAFE_NO_PADDING);
+        assert_eq!(b64.decode_to_unsigned(), Ok(value));
+    }
+
+    #[test]
+    fn bignum_leading_zeros() {
+        let b64 = Base64::new_encode_bignum(&[0, 0, 1, 0, 2], URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "QAC");
+        assert_eq!(b64.decode_to_bignum(), vec![1, 0, 2]);
+        let b64 = Base64::new_encode_bignum(&[0, 0, 0], URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "A");
+        assert_eq!(b64.decode_to_bignum(), vec![0]);
+    }
+
+    #[test]
+    fn bignum_round_trip_40_bytes() {
+        let mut bytes: Vec<u8> = Vec::new();
+        for i in 0..40u8 {
+            bytes.push(i.wrapping_mul(37).wrapping_add(1));
+        }
+        let b64 = Base64::new_encode_bignum(&bytes, STANDARD);
+        assert_eq!(b64.decode_to_bignum(), bytes);
+    }
+
+    #[test]
+    fn stream_encoder_odd_chunks() {
+        let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Dictum fusce ut placerat orci nulla pellentesque. Consequat mauris nunc congue nisi vitae suscipit tellus mauris a.";
+        for conf in &[MIME, STANDARD, URL_SAFE_NO_PADDING] {
+            for chunk_size in &[1, 2, 4, 5, 7, 11] {
+                let mut encoder = Encoder::new(Vec::new(), conf);
+                for chunk in s.as_bytes().chunks(*chunk_size) {
+                    encoder.write_all(chunk).unwrap();
+                }
+                let encoded = String::from_utf8(encoder.finish().unwrap()).unwrap();
+                assert_eq!(
+                    encoded,
+                    Base64::new_encode_bytes(s.as_bytes(), conf).to_string()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn stream_encoder_padding_on_finish() {
+        let mut encoder = Encoder::new(Vec::new(), MIME);
+        encoder.write_all(b"Hi").unwrap();
+        encoder.flush().unwrap();
+        let encoded = encoder.finish().unwrap();
+        assert_eq!(String::from_utf8(encoded).unwrap(), "SGk=");
+    }
+
+    #[test]
+    fn base64_encode_no_padding() {
+        let b64: Base64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_NO_PADDING);
+        assert_eq!("SGk", b64.to_string());
+        let b64: Base64 = Base64::new_encode_bytes("H".as_bytes(), URL_SAFE_NO_PADDING);
+        assert_eq!("SA", b64.to_string());
+    }
+
+    #[test]
+    fn free_encode_decode() {
+        assert_eq!(lb64::encode(b"Hello"), "SGVsbG8=");
+        assert_eq!(
+            lb64::encode_config(b"Hello", URL_SAFE_NO_PADDING),
+            "SGVsbG8"
+        );
+        assert_eq!(lb64::decode("SGVsbG8="), Ok(b"Hello".to_vec()));
+        assert_eq!(
+            lb64::decode_config("SGVsbG8", URL_SAFE_NO_PADDING),
+            Ok(b"Hello".to_vec())
+        );
+    }
+
+    #[test]
+    fn free_decode_invalid_character() {
+        assert_eq!(
+            lb64::decode("SGVs-G8="),
+            Err(Base64Error::InvalidBase64CharacterError {
+                character: '-',
+                index: 4
+            })
+        );
+    }
+
+    #[test]
+    fn base64_decode_zero_bytes() {
+        let bytes: &[u8] = &[0, 104, 0, 0, 105, 0];
+        let b64 = Base64::new_encode_bytes(bytes, STANDARD);
+        assert_eq!(b64.decode_to_bytes(), bytes);
+        let b64 = Base64::new_encode_bytes(&[104, 0], URL_SAFE_NO_PADDING);
+        assert_eq!(b64.decode_to_bytes(), vec![104, 0]);
+    }
+
+    #[test]
+    fn invalid_character_position() {
+        match Base64::new_from_string("SGé=", STANDARD) {
+            Ok(val) => panic!("{} shouldn't be valid", val),
+            Err(Base64Error::InvalidBase64CharacterError { character, index }) => {
+                assert_eq!(character, 'é');
+                assert_eq!(index, 2);
+            }
+            Err(e) => panic!("Unexpected error {}", e),
+        }
+        let e = Base64::new_from_string("SGé=", STANDARD).unwrap_err();
+        assert_eq!(e.to_string(), "Invalid character 'é' at index 2");
+    }
+
+    #[test]
+    fn new_from_string_whitespace_with_line_length() {
+        let b64 = Base64::new_from_string("SGVs\nbG8=", MIME).unwrap();
+        assert_eq!(b64.decode_to_bytes(), b"Hello");
+        assert!(Base64::new_from_string("SGVs bG8=", MIME).is_ok());
+    }
+
+    #[test]
+    fn new_from_string_whitespace_without_line_length() {
+        match Base64::new_from_string("ab cd", STANDARD) {
+            Ok(val) => panic!("{} shouldn't be valid", val),
+            Err(e) => assert_eq!(
+                e,
+                Base64Error::InvalidBase64CharacterError {
+                    character: ' ',
+                    index: 2
+                }
+            ),
+        }
+        assert!(Base64::new_from_string("SGVs\nbG8=", STANDARD).is_err());
+    }
+
+    #[test]
+    fn base64_decode_crlf() {
+        let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        let b64 = Base64::new_encode_bytes(s.as_bytes(), MIME);
+        assert!(b64.to_string().contains("\r\n"));
+        let decoded = Base64::new_from_string(b64.as_str(), MIME).unwrap();
+        assert_eq!(decoded.decode_to_bytes(), s.as_bytes());
+    }
+
+    #[test]
+    fn rewrap_line_length() {
+        let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        let conf_76: Config = MIME.clone();
+        let mut conf_20: Config = MIME.clone();
+        conf_20.set_line_length(Some(20));
+        let mut b64 = Base64::new_encode_bytes(s.as_bytes(), &conf_76);
+        let unwrapped: String = b64.to_string().replace("\r\n", "");
+        b64.set_config(&conf_20);
+        b64.rewrap();
+        let wrapped = b64.to_string();
+        let lines: Vec<&str> = wrapped.split("\r\n").collect();
+        assert_eq!(lines.concat(), unwrapped);
+        for line in &lines[..lines.len() - 1] {
+            assert_eq!(line.len(), 20);
+        }
+        assert_eq!(b64.decode_to_bytes(), s.as_bytes());
+        b64.set_config(&conf_76);
+        b64.rewrap();
+        assert_eq!(b64, Base64::new_encode_bytes(s.as_bytes(), &conf_76));
+    }
+
+    #[test]
+    fn rewrap_no_line_length() {
+        let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        let mut b64 = Base64::new_encode_bytes(s.as_bytes(), MIME);
+        b64.set_config(STANDARD);
+        b64.rewrap();
+        assert_eq!(b64, Base64::new_encode_bytes(s.as_bytes(), STANDARD));
+    }
+
+    #[test]
+    fn config_wide_line_length() {
+        let conf = Config::builder()
+            .alphabet(STANDARD.get_character_set())
+            .padding('=')
+            .line_length(1000)
+            .build()
+            .unwrap();
+        assert_eq!(conf.get_line_length(), Some(1000));
+        let bytes: Vec<u8> = (0..1500).map(|i| (i % 256) as u8).collect();
+        let b64 = Base64::new_encode_bytes(&bytes, &conf);
+        let encoded = b64.to_string();
+        let lines: Vec<&str> = encoded.split('\n').collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].len(), 1000);
+        assert_eq!(b64.decode_to_bytes(), bytes);
+    }
+
+    #[test]
+    fn set_config_bytes_padding() {
+        let mut b64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "SGk");
+        b64.set_config(URL_SAFE_PADDING);
+        assert_eq!(b64.to_string(), "SGk=");
+        b64.set_config(URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "SGk");
+        let mut b64 = Base64::new_encode_bytes("H".as_bytes(), URL_SAFE_PADDING);
+        assert_eq!(b64.to_string(), "SA==");
+        b64.set_config(URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "SA");
+        b64.set_config(STANDARD);
+        assert_eq!(b64.to_string(), "SA==");
+        assert_eq!(b64.decode_to_bytes(), b"H");
+    }
+
+    #[test]
+    fn set_config_bytes_padding_wrapped() {
+        let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        let mut b64 = Base64::new_encode_bytes(s.as_bytes(), MIME);
+        let padded = b64.to_string();
+        b64.set_config(URL_SAFE_NO_PADDING);
+        assert!(!b64.to_string().ends_with('='));
+        b64.set_config(MIME);
+        assert_eq!(b64.to_string(), padded);
+    }
+
+    #[test]
+    fn set_config_numeric_padding() {
+        let mut b64 = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING);
+        b64.set_config(URL_SAFE_PADDING);
+        assert_eq!(b64.to_string(), "_");
+        b64.set_config(STANDARD);
+        assert_eq!(b64.to_string(), "/");
+        b64.set_config(URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "_");
+        assert_eq!(b64.decode_to_unsigned(), Ok(63));
+    }
+
+    #[test]
+    fn without_padding() {
+        let b64 = Base64::new_encode_bytes("Hi".as_bytes(), MIME);
+        assert_eq!(b64.to_string(), "SGk=");
+        let stripped = b64.without_padding();
+        assert_eq!(stripped.to_string(), "SGk");
+        assert_eq!(stripped.decode_to_bytes(), b"Hi");
+        assert_eq!(b64.to_string(), "SGk=");
+    }
+
+    #[test]
+    fn strip_padding() {
+        let mut b64 = Base64::new_encode_bytes("H".as_bytes(), STANDARD);
+        b64.strip_padding();
+        assert_eq!(b64.to_string(), "SA");
+        let mut b64 = Base64::new_encode_bytes("Hi".as_bytes(), URL_SAFE_NO_PADDING);
+        b64.strip_padding();
+        assert_eq!(b64.to_string(), "SGk");
+    }
+
+    #[test]
+    fn padding_count() {
+        let b64 = Base64::new_encode_bytes("H".as_bytes(), MIME);
+        assert_eq!(b64.to_string(), "SA==");
+        assert!(b64.is_padded());
+        assert_eq!(b64.padding_count(), 2);
+        let b64 = Base64::new_encode_bytes("H".as_bytes(), URL_SAFE_NO_PADDING);
+        assert!(!b64.is_padded());
+        assert_eq!(b64.padding_count(), 0);
+    }
+
+    #[test]
+    fn padding_count_only_trailing() {
+        let b64 = Base64::new_from_string("S=A", URL_SAFE_PADDING).unwrap();
+        assert_eq!(b64.to_string(), "S=A==");
+        assert_eq!(b64.padding_count(), 2);
+        let b64 = Base64::new_encode_bytes("Hi!".as_bytes(), MIME);
+        assert!(!b64.is_padded());
+    }
+
+    #[test]
+    fn encoded_len_matches() {
+        let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Dictum fusce ut placerat orci nulla pellentesque.";
+        for conf in &[MIME, STANDARD, URL_SAFE_NO_PADDING, IMAP] {
+            for n in &[0, 1, 2, 3, 4, 5, 56, 57, 58, 100, 114, s.len()] {
+                let bytes = &s.as_bytes()[..*n];
+                let b64 = Base64::new_encode_bytes(bytes, conf);
+                assert_eq!(lb64::encoded_len(*n, conf), b64.len());
+                assert_eq!(b64.decoded_len(), *n);
+            }
+        }
+    }
+
+    #[test]
+    fn checked_add() {
+        let ten = Base64::new_from_string("K", URL_SAFE_NO_PADDING).unwrap();
+        let one = Base64::new_from_string("B", URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(ten.checked_add(&one).unwrap().to_string(), "L");
+        assert_eq!((ten + one).to_string(), "L");
+    }
+
+    #[test]
+    fn checked_add_mismatch_and_overflow() {
+        let ten = Base64::new_encode_unsigned(&10, URL_SAFE_NO_PADDING);
+        let one = Base64::new_encode_unsigned(&1, STANDARD);
+        assert_eq!(ten.checked_add(&one), None);
+        let max = Base64::new_encode_unsigned(&u128::MAX, STANDARD);
+        assert_eq!(max.checked_add(&one), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow_panics() {
+        let max = Base64::new_encode_unsigned(&u128::MAX, STANDARD);
+        let _ = max + Base64::new_encode_unsigned(&1, STANDARD);
+    }
+
+    #[test]
+    fn increment() {
+        let mut b64 = Base64::new_from_string("__", URL_SAFE_NO_PADDING).unwrap();
+        b64.increment();
+        assert_eq!(b64.to_string(), "BAA");
+        b64.increment();
+        assert_eq!(b64.to_string(), "BAB");
+        let mut b64 = Base64::new_random(40, URL_SAFE_NO_PADDING);
+        let before = b64.clone();
+        b64.increment();
+        b64.decrement();
+        assert_eq!(
+            b64.digits().collect::<Vec<u128>>(),
+            before.digits().collect::<Vec<u128>>()
+        );
+    }
+
+    #[test]
+    fn decrement() {
+        let mut b64 = Base64::new_from_string("A", URL_SAFE_NO_PADDING).unwrap();
+        b64.decrement();
+        assert_eq!(b64.to_string(), "A");
+        let mut b64 = Base64::new_from_string("BAA", URL_SAFE_NO_PADDING).unwrap();
+        b64.decrement();
+        assert_eq!(b64.to_string(), "__");
+        b64.decrement();
+        assert_eq!(b64.to_string(), "_-");
+    }
+
+    #[test]
+    fn bitwise_bytes() {
+        let lhs = Base64::new_encode_bytes("Hello".as_bytes(), STANDARD);
+        let rhs = Base64::new_encode_bytes("World".as_bytes(), URL_SAFE_NO_PADDING);
+        assert_eq!(lhs.bitxor_bytes(&rhs), vec![31, 10, 30, 0, 11]);
+        assert_eq!(lhs.bitand_bytes(&rhs), vec![64, 101, 96, 108, 100]);
+        assert_eq!(lhs.bitor_bytes(&rhs), vec![95, 111, 126, 108, 111]);
+    }
+
+    #[test]
+    fn bitwise_bytes_different_lengths() {
+        let lhs = Base64::new_encode_bytes(&[0xFF, 0x0F, 0xF0], STANDARD);
+        let rhs = Base64::new_encode_bytes(&[0xFF], STANDARD);
+        assert_eq!(lhs.bitxor_bytes(&rhs), vec![0xFF, 0x0F, 0x0F]);
+        assert_eq!(rhs.bitand_bytes(&lhs), vec![0x00, 0x00, 0xF0]);
+        assert_eq!(rhs.bitor_bytes(&lhs), vec![0xFF, 0x0F, 0xFF]);
+    }
+
+    #[test]
+    fn shift_left() {
+        let mut b64 = Base64::new_from_string("B", URL_SAFE_NO_PADDING).unwrap();
+        b64.shift_left(1);
+        assert_eq!(b64.to_string(), "BA");
+        assert_eq!(b64.decode_to_unsigned(), Ok(64));
+        b64.shift_left(2);
+        assert_eq!(b64.decode_to_unsigned(), Ok(64 * 64 * 64));
+    }
+
+    #[test]
+    fn shift_right() {
+        let mut b64 = Base64::new_from_string("BAB", URL_SAFE_NO_PADDING).unwrap();
+        b64.shift_right(1);
+        assert_eq!(b64.to_string(), "BA");
+        b64.shift_right(2);
+        assert_eq!(b64.to_string(), "A");
+        let mut b64 = Base64::new_from_string("SGk", URL_SAFE_PADDING).unwrap();
+        b64.shift_right(1);
+        assert_eq!(b64.to_string(), "SG==");
+    }
+
+    #[test]
+    fn pem_line_length() {
+        let bytes: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        let b64 = Base64::new_encode_bytes(&bytes, PEM);
+        let encoded = b64.to_string();
+        let lines: Vec<&str> = encoded.split('\n').collect();
+        assert_eq!(lines.len(), 5);
+        for line in &lines[..lines.len() - 1] {
+            assert_eq!(line.len(), 64);
+        }
+        assert_eq!(lines.concat(), lb64::encode(&bytes));
+        assert_eq!(b64.decode_to_bytes(), bytes);
+    }
+
+    #[test]
+    fn bcrypt_encode() {
+        let b64 = Base64::new_encode_bytes("Hello, World".as_bytes(), BCRYPT);
+        assert_eq!(b64.to_string(), "QETqZE6qGDbtakvi");
+        assert_eq!(b64.decode_to_bytes(), b"Hello, World");
+        let b64 = Base64::new_encode_bytes(&[0, 1, 254, 255], BCRYPT);
+        assert_eq!(b64.to_string(), "..F89u");
+        assert_eq!(b64.decode_to_bytes(), vec![0, 1, 254, 255]);
+    }
+
+    #[test]
+    fn crypt_encode() {
+        let b64 = Base64::new_encode_bytes("Hello, World".as_bytes(), CRYPT);
+        assert_eq!(b64.to_string(), "G4JgP4wg63RjQalY");
+        assert_eq!(b64.decode_to_bytes(), b"Hello, World");
+        let b64 = Base64::new_encode_bytes(&[0, 1, 254, 255], CRYPT);
+        assert_eq!(b64.to_string(), "..5yzk");
+        assert_eq!(b64.decode_to_bytes(), vec![0, 1, 254, 255]);
+    }
+
+    #[test]
+    fn bcrypt_crypt_configs_valid() {
+        for conf in &[BCRYPT, CRYPT] {
+            let checked = Config::new(conf.get_character_set(), None, None).unwrap();
+            assert_eq!(&checked, *conf);
+        }
+    }
+
+    #[test]
+    fn y64_round_trip() {
+        let b64 = Base64::new_encode_bytes(&[0xFB, 0xFF], Y64);
+        assert_eq!(b64.to_string(), "._8-");
+        assert_eq!(b64.decode_to_bytes(), vec![0xFB, 0xFF]);
+        let s: &str = "Lorem ipsum dolor sit amet";
+        let b64 =
+            Base64::new_from_string(Base64::new_encode_bytes(s.as_bytes(), Y64).as_str(), Y64)
+                .unwrap();
+        assert_eq!(b64.decode_to_bytes(), s.as_bytes());
+    }
+
+    #[test]
+    fn y64_config_valid() {
+        let conf = Config::new(Y64.get_character_set(), Some('-'), None).unwrap();
+        assert_eq!(&conf, Y64);
+    }
+
+    #[test]
+    fn config_from_alphabet_str() {
+        let owned = Config::from_alphabet_str(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Some('='),
+            None,
+        )
+        .unwrap();
+        assert_eq!(&owned.as_config(), STANDARD);
+        let emoji: String = (0..64)
+            .map(|i| std::char::from_u32(0x1F600 + i).unwrap())
+            .collect();
+        assert_eq!(emoji.len(), 256);
+        let owned = Config::from_alphabet_str(&emoji, None, None).unwrap();
+        assert_eq!(owned.as_config().get_character_set().len(), 64);
+    }
+
+    #[test]
+    fn config_from_alphabet_str_length() {
+        assert_eq!(
+            Config::from_alphabet_str("ABC", None, None),
+            Err(ConfigError::CharacterSetLengthError)
+        );
+        let emoji: String = (0..65)
+            .map(|i| std::char::from_u32(0x1F600 + i).unwrap())
+            .collect();
+        assert_eq!(
+            Config::from_alphabet_str(&emoji, None, None),
+            Err(ConfigError::CharacterSetLengthError)
+        );
+    }
+
+    #[test]
+    fn detect_config_url_safe() {
+        let configs = lb64::config::detect_config("PDw_Pz4-");
+        assert_eq!(configs, vec![URL_SAFE_PADDING, URL_SAFE_NO_PADDING]);
+    }
+
+    #[test]
+    fn detect_config_standard() {
+        let configs = lb64::config::detect_config("PDw/Pz4+\nPz8=");
+        assert_eq!(configs, vec![STANDARD, MIME]);
+        let configs = lb64::config::detect_config("SGVsbG8");
+        assert_eq!(configs.len(), 5);
+        assert!(lb64::config::detect_config("^_^").is_empty());
+    }
+
+    #[test]
+    fn detect_config_only_rfc_configs() {
+        // Digits 0 and 62 include '.' which only BCRYPT, CRYPT, Y64, and XML_NMTOKEN have
+        for conf in &[BCRYPT, CRYPT, Y64, XML_NMTOKEN] {
+            let b64 = Base64::new_encode_bytes(&[0x00, 0x0F, 0xBE], conf);
+            assert!(
+                lb64::config::detect_config(b64.as_str()).is_empty(),
+                "{}",
+                b64
+            );
+        }
+        // Characters every built-in config has
+        let configs = lb64::config::detect_config("SGVsbG8");
+        for conf in &[PEM, BCRYPT, CRYPT, Y64, XML_NMTOKEN] {
+            assert!(!configs.contains(conf));
+        }
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let b64 = Base64::from_hex("48656c6c6f", MIME).unwrap();
+        assert_eq!(b64.to_string(), "SGVsbG8=");
+        assert_eq!(b64.decode_to_bytes(), b"Hello");
+        assert_eq!(b64.to_hex_string(), "48656c6c6f");
+        let b64 = Base64::from_hex("00FFfe", STANDARD).unwrap();
+        assert_eq!(b64.to_hex_string(), "00fffe");
+    }
+
+    #[test]
+    fn from_hex_invalid() {
+        let b64 = Base64::from_hex("", STANDARD).unwrap();
+        assert_eq!(b64.to_string(), "");
+        assert_eq!(
+            Base64::from_hex("486", STANDARD),
+            Err(Base64Error::InvalidHexError)
+        );
+        assert_eq!(
+            Base64::from_hex("48zz", STANDARD),
+            Err(Base64Error::InvalidHexError)
+        );
+        assert_eq!(
+            Base64::from_hex("éé", STANDARD),
+            Err(Base64Error::InvalidHexError)
+        );
+    }
+
+    #[test]
+    fn to_binary_string() {
+        let b64 = Base64::new_from_string("SGk=", MIME).unwrap();
+        assert_eq!(b64.to_binary_string(), "0100100001101001");
+        let b64 = Base64::new_encode_bytes(&[], MIME);
+        assert_eq!(b64.to_binary_string(), "");
+    }
+
+    #[test]
+    fn encode_unsigned_min_width() {
+        let b64 = Base64::new_encode_unsigned_min_width(&10, 4, URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "AAAK");
+        assert_eq!(b64.decode_to_unsigned(), Ok(10));
+        let b64 = Base64::new_encode_unsigned_min_width(&4096, 2, URL_SAFE_PADDING);
+        assert_eq!(b64.to_string(), "BAA");
+        let b64 = Base64::new_encode_unsigned_min_width(&0, 3, URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "AAA");
+    }
+
+    #[test]
+    fn decode_to_bytes_ct() {
+        let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        for conf in &[MIME, STANDARD, URL_SAFE_NO_PADDING, IMAP, BCRYPT, Y64] {
+            for n in 0..20 {
+                let b64 = Base64::new_encode_bytes(&s.as_bytes()[..n * 6], conf);
+                assert_eq!(b64.decode_to_bytes_ct(), Ok(b64.decode_to_bytes()));
+            }
+        }
+        let b64 = Base64::new_random(100, STANDARD);
+        assert_eq!(b64.decode_to_bytes_ct(), b64.try_decode_to_bytes());
+    }
+
+    #[test]
+    fn decode_to_bytes_ct_invalid() {
+        let mut b64 = Base64::default();
+        b64.set_config(URL_SAFE_NO_PADDING);
+        assert!(b64.set_from_string("SG\0k"));
+        assert_eq!(
+            b64.decode_to_bytes_ct(),
+            Err(Base64Error::InvalidBase64CharacterError {
+                character: '\0',
+                index: 2
+            })
+        );
+    }
+
+    #[test]
+    fn digit_lookup_unknown_character() {
+        // digit_of is crate internal, so an unknown character shows up as an invalid character
+        assert_eq!(
+            Base64::new_encode_bytes(b"Hi", STANDARD)
+                .chars()
+                .collect::<String>(),
+            "SGk="
+        );
+        let mut b64 = Base64::default();
+        assert!(b64.set_from_string("SGk="));
+        assert_eq!(b64.decode_to_bytes(), b"Hi");
+        assert!(lb64::decode("SG~=").is_err());
+        assert!(lb64::decode_config("😀A", STANDARD).is_err());
+    }
+
+    #[test]
+    fn decode_to_unsigned_leading_zeros() {
+        let b64 = Base64::new_encode_unsigned_min_width(&10, 40, URL_SAFE_NO_PADDING);
+        assert_eq!(b64.decode_to_unsigned(), Ok(10));
+    }
+
+    #[test]
+    fn decode_to_bytes_strict_canonical() {
+        let b64 = Base64::new_encode_bytes(b"Hi", STANDARD);
+        assert_eq!(b64.decode_to_bytes_strict().unwrap(), b"Hi");
+        let b64 = Base64::new_from_string("SA==", STANDARD).unwrap();
+        assert_eq!(b64.decode_to_bytes_strict().unwrap(), b"H");
+        let b64 = Base64::new_encode_bytes(b"Hello", URL_SAFE_NO_PADDING);
+        assert_eq!(b64.decode_to_bytes_strict().unwrap(), b"Hello");
+        let b64 = Base64::new_encode_bytes(&[0u8; 100], MIME);
+        assert_eq!(b64.decode_to_bytes_strict().unwrap(), vec![0u8; 100]);
+    }
+
+    #[test]
+    fn decode_to_bytes_strict_non_canonical() {
+        // Unused bits of the last digit aren't 0
+        let b64 = Base64::new_from_string("SG==", STANDARD).unwrap();
+        assert_eq!(b64.decode_to_bytes(), b"H");
+        assert_eq!(
+            b64.decode_to_bytes_strict(),
+            Err(Base64Error::NonCanonicalEncoding)
+        );
+        let b64 = Base64::new_from_string("SGl", URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(
+            b64.decode_to_bytes_strict(),
+            Err(Base64Error::NonCanonicalEncoding)
+        );
+        // Padding before digits
+        let b64 = Base64::new_from_string("=SGk", STANDARD).unwrap();
+        assert_eq!(
+            b64.decode_to_bytes_strict(),
+            Err(Base64Error::NonCanonicalEncoding)
+        );
+        // A single digit in the last group
+        let b64 = Base64::new_from_string("SGkhS", URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(
+            b64.decode_to_bytes_strict(),
+            Err(Base64Error::NonCanonicalEncoding)
+        );
+    }
+
+    #[test]
+    fn ignore_whitespace_defaults() {
+        assert!(MIME.get_ignore_whitespace());
+        assert!(PEM.get_ignore_whitespace());
+        assert!(!STANDARD.get_ignore_whitespace());
+        assert!(!URL_SAFE_PADDING.get_ignore_whitespace());
+        assert!(!URL_SAFE_NO_PADDING.get_ignore_whitespace());
+    }
+
+    #[test]
+    fn ignore_whitespace_decode() {
+        let b64 = Base64::new_from_string("SG k=", MIME).unwrap();
+        assert_eq!(b64.try_decode_to_bytes().unwrap(), b"Hi");
+        assert!(Base64::new_from_string("SG k=", URL_SAFE_PADDING).is_err());
+    }
+
+    #[test]
+    fn ignore_whitespace_strict() {
+        let mut strict: Config = MIME.clone();
+        strict.set_ignore_whitespace(false);
+        assert!(!strict.get_ignore_whitespace());
+        assert!(Base64::new_from_string("SG k=", &strict).is_err());
+        let mut b64 = Base64::new_from_string("SG k=", MIME).unwrap();
+        b64.set_config(&strict);
+        match b64.try_decode_to_bytes() {
+            Err(Base64Error::InvalidBase64CharacterError { character, index }) => {
+                assert_eq!(character, ' ');
+                assert_eq!(index, 2);
+            }
+            _ => panic!("whitespace should be invalid"),
+        }
+        assert!(b64.decode_to_bytes_ct().is_err());
+        assert!(b64.decode_to_bytes_strict().is_err());
+        assert!(b64.decode_to_unsigned().is_err());
+    }
+
+    #[test]
+    fn config_default() {
+        let conf = Config::default();
+        assert_eq!(conf, *STANDARD);
+        assert_eq!(Base64::new_encode_unsigned(&63, &conf).to_string(), "/");
+    }
+
+    #[test]
+    fn config_error_boxed() {
+        use std::error::Error;
+        let err: Box<dyn Error> = Box::new(ConfigError::CharacterSetLengthError);
+        assert_eq!(err.to_string(), "Provided Character set length isn't 64");
+        assert!(err.source().is_none());
+        let err: Box<dyn Error> = Base64::new_from_string("^", STANDARD).unwrap_err().into();
+        assert_eq!(format!("{}", err), "Invalid character '^' at index 0");
+    }
+
+    #[test]
+    fn crate_error_from() {
+        let err: lb64::error::Error = ConfigError::DuplicateCharacterError.into();
+        assert_eq!(
+            err,
+            lb64::error::Error::Config(ConfigError::DuplicateCharacterError)
+        );
+        assert_eq!(
+            err.to_string(),
+            "At least one duplicate character found in character set"
+        );
+        let err: lb64::error::Error = Base64::new_from_string("^", STANDARD).unwrap_err().into();
+        assert_eq!(
+            err,
+            lb64::error::Error::Base64(Base64Error::InvalidBase64CharacterError {
+                character: '^',
+                index: 0
+            })
+        );
+        assert_eq!(err.to_string(), "Invalid character '^' at index 0");
+    }
+
+    #[test]
+    fn try_new_random_length() {
+        for len in 0..=21 {
+            let b64 = Base64::try_new_random(len, URL_SAFE_NO_PADDING).unwrap();
+            // A length of 0 is "A"
+            assert_eq!(b64.len(), len.max(1));
+            assert!(b64.decode_to_unsigned().is_ok());
+        }
+        // Longer byte-style values are still created
+        for len in &[22, 100, 1000] {
+            let b64 = Base64::try_new_random(*len, URL_SAFE_NO_PADDING).unwrap();
+            assert_eq!(b64.len(), *len);
+        }
+        let b64 = Base64::try_new_random(22, STANDARD).unwrap();
+        assert_eq!(b64.len(), 24);
+        assert_eq!(
+            Base64::try_new_random(usize::MAX, URL_SAFE_NO_PADDING),
+            Err(Base64Error::OverflowError)
+        );
+        assert_eq!(
+            Base64::try_new_random(usize::MAX / 2, URL_SAFE_NO_PADDING),
+            Err(Base64Error::OverflowError)
+        );
+        assert_eq!(
+            Base64::try_new_random(usize::MAX / 2, STANDARD),
+            Err(Base64Error::OverflowError)
+        );
+    }
+
+    #[test]
+    fn new_random_numeric_round_trip() {
+        for max in &[0, 1, 63, 64, 1000, u64::MAX as u128, u128::MAX] {
+            for _ in 0..100 {
+                let b64 = Base64::new_random_numeric(*max, STANDARD);
+                assert!(b64.decode_to_unsigned().unwrap() <= *max);
+                assert!(!b64.is_padded());
+            }
+        }
+    }
+
+    #[test]
+    fn new_random_with_seed() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        let first = Base64::new_random_with(40, MIME, &mut StdRng::seed_from_u64(42));
+        let second = Base64::new_random_with(40, MIME, &mut StdRng::seed_from_u64(42));
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 40);
+        let mut third = Base64::default();
+        third.set_random_with(40, &mut StdRng::seed_from_u64(42));
+        assert_eq!(third.to_string(), first.to_string());
+    }
+
+    #[test]
+    fn concat_digits() {
+        let one = Base64::new_from_string("B", URL_SAFE_NO_PADDING).unwrap();
+        let zero = Base64::new_from_string("A", URL_SAFE_NO_PADDING).unwrap();
+        let b64 = one.concat(&zero).unwrap();
+        assert_eq!(b64.to_string(), "BA");
+        assert_eq!(b64.decode_to_unsigned(), Ok(64));
+        // Interior padding is removed
+        let lhs = Base64::new_encode_bytes(b"H", STANDARD);
+        let rhs = Base64::new_encode_bytes(b"i", STANDARD);
+        assert_eq!(lhs.concat(&rhs).unwrap().to_string(), "SAaQ");
+        assert_eq!(
+            one.concat(&Base64::new_encode_unsigned(&0, STANDARD)),
+            Err(Base64Error::ConfigMismatchError)
+        );
+    }
+
+    #[test]
+    fn split_at_digits() {
+        let b64 = Base64::new_from_string("abcd", URL_SAFE_NO_PADDING).unwrap();
+        let (high, low) = b64.split_at(2);
+        assert_eq!(high.to_string(), "ab");
+        assert_eq!(low.to_string(), "cd");
+        let (high, low) = b64.split_at(4);
+        assert_eq!(high.to_string(), "abcd");
+        assert_eq!(low.to_string(), "A");
+        let (high, low) = b64.split_at(0);
+        assert_eq!(high.to_string(), "A");
+        assert_eq!(low.to_string(), "abcd");
+        // Padding is removed then added again
+        let b64 = Base64::new_encode_bytes(b"Hi", STANDARD); // SGk=
+        let (high, low) = b64.split_at(2);
+        assert_eq!(high.to_string(), "SG==");
+        assert_eq!(low.to_string(), "k===");
+    }
+
+    #[test]
+    fn slice_digits() {
+        let b64 = Base64::new_from_string("abcdef", URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(b64.slice(1..4).to_string(), "bcd");
+        assert_eq!(b64.slice(2..100).to_string(), "cdef");
+        assert_eq!(b64.slice(50..100).to_string(), "A");
+    }
+
+    #[test]
+    fn reverse_digits() {
+        let mut b64 = Base64::new_from_string("abc", URL_SAFE_NO_PADDING).unwrap();
+        b64.reverse();
+        assert_eq!(b64.to_string(), "cba");
+        let mut b64 = Base64::new_encode_bytes(b"Hi", STANDARD); // SGk=
+        b64.reverse();
+        assert_eq!(b64.to_string(), "kGS=");
+    }
+
+    #[test]
+    fn as_str_borrows_value() {
+        let b64 = Base64::new_encode_bytes(b"Hi", URL_SAFE_PADDING);
+        assert_eq!(b64.as_str(), "SGk=");
+        assert_eq!(b64.as_str().chars().count(), b64.len());
+        let s: &str = b64.as_ref();
+        assert_eq!(s, b64.to_string());
+        let b64 = Base64::new_encode_bytes("😀".as_bytes(), MIME);
+        assert_eq!(b64.as_str().chars().count(), b64.len());
+    }
+
+    #[test]
+    fn from_conversions() {
+        assert_eq!(
+            Base64::from(128u128),
+            Base64::new_encode_unsigned(&128, STANDARD)
+        );
+        let b64: Base64 = (&b"Hi"[..], MIME).into();
+        assert_eq!(b64.to_string(), "SGk=");
+    }
+
+    #[test]
+    fn le_bytes_round_trip() {
+        for value in &[0u128, 1, 255, 256, 65538, u64::MAX as u128, u128::MAX] {
+            let b64 = Base64::new_encode_unsigned(value, STANDARD);
+            let le = b64.to_le_bytes().unwrap();
+            assert_eq!(Base64::new_encode_le_bytes(&le, STANDARD), b64);
+            let mut expected = [0u8; 16];
+            expected[..le.len()].copy_from_slice(&le);
+            assert_eq!(u128::from_le_bytes(expected), *value);
+        }
+        assert_eq!(
+            Base64::new_encode_unsigned(&65538, STANDARD).to_le_bytes(),
+            Ok(vec![2, 0, 1])
+        );
+        assert_eq!(
+            Base64::new_encode_unsigned(&0, STANDARD).to_le_bytes(),
+            Ok(vec![0])
+        );
+    }
+
+    #[test]
+    fn be_bytes_round_trip() {
+        for value in &[0u128, 1, 255, 256, 65538, u64::MAX as u128, u128::MAX] {
+            let b64 = Base64::new_encode_unsigned(value, STANDARD);
+            let be = b64.to_be_bytes().unwrap();
+            assert_eq!(Base64::new_encode_bignum(&be, STANDARD), b64);
+            assert_eq!(be, b64.decode_to_bignum());
+        }
+        assert_eq!(
+            Base64::new_encode_unsigned(&65538, STANDARD).to_be_bytes(),
+            Ok(vec![1, 0, 2])
+        );
+    }
+
+    #[test]
+    fn validate_url_safe() {
+        assert!(lb64::is_valid("SGVsbG8-_w", URL_SAFE_NO_PADDING));
+        assert!(lb64::validate("SGVsbG8-_w", URL_SAFE_NO_PADDING).is_ok());
+        assert!(lb64::is_valid("", URL_SAFE_NO_PADDING));
+        assert!(!lb64::is_valid("SGVsbG8+/w", URL_SAFE_NO_PADDING));
+        match lb64::validate("SGVsbG8+/w", URL_SAFE_NO_PADDING) {
+            Err(Base64Error::InvalidBase64CharacterError { character, index }) => {
+                assert_eq!(character, '+');
+                assert_eq!(index, 7);
+            }
+            _ => panic!("'+' isn't URL safe"),
+        }
+        assert!(lb64::is_valid("SGk=", URL_SAFE_PADDING));
+        assert!(!lb64::is_valid("SGk=", URL_SAFE_NO_PADDING));
+    }
+
+    #[test]
+    fn config_contains_and_is_padding() {
+        assert!(MIME.contains('A'));
+        assert!(MIME.contains('/'));
+        assert!(!MIME.is_padding('A'));
+        assert!(!MIME.contains('='));
+        assert!(MIME.is_padding('='));
+        assert!(!MIME.contains('-'));
+        assert!(!MIME.is_padding('-'));
+        assert!(!URL_SAFE_NO_PADDING.is_padding('='));
+        assert!(Y64.is_padding('-'));
+        let owned = Config::from_alphabet_str(
+            "😀😁😂🤣😃😄😅😆😉😊😋😎😍😘🥰😗😙😚🙂🤗🤩🤔🤨😐😑😶🙄😏😣😥😮🤐😯😪😫😴😌😛😜😝🤤😒😓😔😕🙃🤑😲🙁😖😞😟😤😢😭😦😧😨😩🤯😬😰😱🥵",
+            None,
+            None,
+        )
+        .unwrap();
+        let conf = owned.as_config();
+        assert!(conf.contains('😀'));
+        assert!(!conf.contains('A'));
+    }
+
+    #[test]
+    fn mod_u64_long_value() {
+        let b64 = Base64::new_from_string(
+            "1kkc464r3cJ7hZ8FgOURPRsvuddy58GTCqgoiBW3JhBXU33Q1p",
+            URL_SAFE_NO_PADDING,
+        )
+        .unwrap();
+        assert_eq!(b64.decode_to_unsigned(), Err(Base64Error::OverflowError));
+        // Reference values computed with Python's arbitrary precision integers
+        assert_eq!(b64.mod_u64(1), 0);
+        assert_eq!(b64.mod_u64(2), 1);
+        assert_eq!(b64.mod_u64(7), 3);
+        assert_eq!(b64.mod_u64(1_000_000_007), 570_220_277);
+        assert_eq!(b64.mod_u64(u64::MAX), 18_260_474_639_035_767_011);
+        assert_eq!(
+            b64.mod_u64(9_223_372_036_854_775_813),
+            2_536_381_007_868_645_164
+        );
+        assert_eq!(b64.mod_u64(0), 0);
+    }
+
+    #[test]
+    fn mod_u64_skips_padding() {
+        let b64 = Base64::new_encode_bytes(b"Hi", MIME); // SGk=
+        let value = Base64::new_encode_bytes(b"Hi", URL_SAFE_NO_PADDING)
+            .decode_to_unsigned()
+            .unwrap();
+        assert_eq!(u128::from(b64.mod_u64(1000)), value % 1000);
+    }
+
+    #[test]
+    fn checked_mul_digits() {
+        let b64 = Base64::new_from_string("BA", URL_SAFE_NO_PADDING).unwrap();
+        let product = b64.checked_mul(&b64).unwrap();
+        assert_eq!(product.to_string(), "BAA");
+        assert_eq!(product.decode_to_unsigned(), Ok(4096));
+        let one = Base64::new_from_string("B", URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(one.checked_mul(&one).unwrap().to_string(), "B");
+        let zero = Base64::new_encode_unsigned(&0, URL_SAFE_NO_PADDING);
+        assert_eq!(b64.checked_mul(&zero).unwrap().to_string(), "A");
+        for (a, b) in &[
+            (12345u128, 6789u128),
+            (u64::MAX as u128, u64::MAX as u128),
+            (63, 63),
+        ] {
+            let lhs = Base64::new_encode_unsigned(a, URL_SAFE_NO_PADDING);
+            let rhs = Base64::new_encode_unsigned(b, URL_SAFE_NO_PADDING);
+            assert_eq!(
+                lhs.checked_mul(&rhs).unwrap().decode_to_unsigned(),
+                Ok(a * b)
+            );
+        }
+        assert!(b64
+            .checked_mul(&Base64::new_encode_unsigned(&1, MIME))
+            .is_none());
+    }
+
+    #[test]
+    fn checked_pow_digits() {
+        let two = Base64::new_encode_unsigned(&2, URL_SAFE_NO_PADDING);
+        assert_eq!(two.checked_pow(0).unwrap().to_string(), "B");
+        assert_eq!(two.checked_pow(12).unwrap().decode_to_unsigned(), Ok(4096));
+        assert_eq!(
+            two.checked_pow(127).unwrap().decode_to_unsigned(),
+            Ok(1u128 << 127)
+        );
+        // 2^132 is 1 followed by 22 0 digits
+        let big = two.checked_pow(132).unwrap();
+        assert_eq!(big.len(), 23);
+        assert!(big.to_string().starts_with('B'));
+        assert_eq!(big.decode_to_unsigned(), Err(Base64Error::OverflowError));
+        let b64 = Base64::new_encode_unsigned(&7, URL_SAFE_NO_PADDING);
+        assert_eq!(b64.checked_pow(5).unwrap().decode_to_unsigned(), Ok(16807));
+    }
+
+    #[test]
+    fn as_cow_str_borrowed() {
+        let b64 = Base64::new_encode_bytes("Hello, World!".as_bytes(), MIME);
+        let cow = b64.as_cow_str();
+        assert!(matches!(cow, std::borrow::Cow::Borrowed(_)));
+        assert_eq!(cow, b64.to_string());
+    }
+
+    #[test]
+    fn encode_iter_matches_new_encode_bytes() {
+        let long = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor";
+        for s in &["", "H", "Hi", "Hel", "Hello", "Hello, World!", long] {
+            for conf in &[STANDARD, MIME, URL_SAFE_NO_PADDING, Y64] {
+                let lazy: String = lb64::encode_iter(s.as_bytes(), conf).collect();
+                assert_eq!(
+                    lazy,
+                    Base64::new_encode_bytes(s.as_bytes(), conf).to_string()
+                );
+            }
+        }
+        assert_eq!(
+            lb64::encode_iter(b"H", STANDARD).collect::<String>(),
+            "SA=="
+        );
+        assert_eq!(
+            lb64::encode_iter(b"Hi", STANDARD).collect::<String>(),
+            "SGk="
+        );
+    }
+
+    #[test]
+    fn decode_iter_hello() {
+        let decoded: Result<Vec<u8>, _> = lb64::decode_iter("SGVsbG8=".chars(), STANDARD).collect();
+        assert_eq!(decoded.unwrap(), b"Hello");
+        let encoded = Base64::new_encode_bytes(&[0u8, 1, 2, 3, 255, 254, 0], MIME).to_string();
+        let decoded: Result<Vec<u8>, _> = lb64::decode_iter(encoded.chars(), MIME).collect();
+        assert_eq!(decoded.unwrap(), vec![0u8, 1, 2, 3, 255, 254, 0]);
+    }
+
+    #[test]
+    fn decode_iter_invalid_character() {
+        let mut iter = lb64::decode_iter("SGVs^G8=".chars(), STANDARD);
+        assert_eq!(iter.next(), Some(Ok(b'H')));
+        assert_eq!(iter.next(), Some(Ok(b'e')));
+        assert_eq!(iter.next(), Some(Ok(b'l')));
+        match iter.next() {
+            Some(Err(Base64Error::InvalidBase64CharacterError { character, index })) => {
+                assert_eq!(character, '^');
+                assert_eq!(index, 4);
+            }
+            _ => panic!("'^' isn't valid"),
+        }
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn empty_is_empty() {
+        let mut b64 = Base64::empty(URL_SAFE_PADDING);
+        assert!(b64.is_empty());
+        assert_eq!(b64.len(), 0);
+        assert_eq!(b64.to_string(), "");
+        assert_eq!(b64.decode_to_unsigned(), Ok(0));
+        b64.increment();
+        assert!(!b64.is_empty());
+        assert_eq!(b64.to_string(), "B");
+        assert!(!Base64::default().is_empty());
+    }
+
+    #[test]
+    fn push_and_pop_digits() {
+        let mut b64 = Base64::empty(URL_SAFE_NO_PADDING);
+        assert_eq!(b64.push_digit(1), Ok(()));
+        assert_eq!(b64.push_digit(0), Ok(()));
+        assert_eq!(b64.to_string(), "BA");
+        assert_eq!(b64.decode_to_unsigned(), Ok(64));
+        assert_eq!(b64.push_digit(64), Err(Base64Error::OverflowError));
+        assert_eq!(b64.to_string(), "BA");
+        assert_eq!(b64.pop_digit(), Some(0));
+        assert_eq!(b64.pop_digit(), Some(1));
+        assert_eq!(b64.pop_digit(), None);
+        assert!(b64.is_empty());
+    }
+
+    #[test]
+    fn push_and_pop_digits_padding() {
+        let mut b64 = Base64::new_encode_bytes(b"Hi", STANDARD); // SGk=
+        assert_eq!(b64.pop_digit(), Some(36));
+        assert_eq!(b64.to_string(), "SG==");
+        b64.push_digit(36).unwrap();
+        assert_eq!(b64.to_string(), "SGk=");
+        b64.push_digit(0).unwrap();
+        assert_eq!(b64.to_string(), "SGkA");
+    }
+
+    #[test]
+    fn case_insensitive_decode() {
+        let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!#$%&()*+,-./:;<>?@[]^_{|}~'";
+        let owned = Config::from_alphabet_str(alphabet, None, None).unwrap();
+        let mut conf = owned.as_config();
+        assert!(!conf.get_case_insensitive());
+        assert!(Base64::new_from_string("hello", &conf).is_err());
+        conf.set_case_insensitive(true).unwrap();
+        assert!(conf.get_case_insensitive());
+        let upper = Base64::new_from_string("HELLO", &conf).unwrap();
+        let lower = Base64::new_from_string("hello", &conf).unwrap();
+        let mixed = Base64::new_from_string("HeLlO", &conf).unwrap();
+        assert_eq!(lower.to_string(), "HELLO");
+        assert_eq!(lower.decode_to_unsigned(), upper.decode_to_unsigned());
+        assert_eq!(mixed.decode_to_bytes(), upper.decode_to_bytes());
+        assert_eq!(lower[0], 'H');
+        let decoded: Result<Vec<u8>, _> = lb64::decode_iter("hello".chars(), &conf).collect();
+        assert_eq!(decoded.unwrap(), upper.decode_to_bytes());
+        // Encoding uses the case of the character set
+        assert_eq!(Base64::new_encode_unsigned(&7, &conf).to_string(), "H");
+    }
+
+    #[test]
+    fn case_insensitive_ambiguous() {
+        let mut conf: Config = STANDARD.clone();
+        assert_eq!(
+            conf.set_case_insensitive(true),
+            Err(ConfigError::AmbiguousCaseError)
+        );
+        assert!(!conf.get_case_insensitive());
+        assert_eq!(conf.set_case_insensitive(false), Ok(()));
+        let alphabet: Vec<char> =
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!#$%&()*+,-./:;<>?@[]^_{|}~'"
+                .chars()
+                .collect();
+        let mut conf = Config::new(&alphabet, None, None).unwrap();
+        conf.set_case_insensitive(true).unwrap();
+        assert_eq!(
+            conf.set_character_set(STANDARD.get_character_set()),
+            Err(ConfigError::AmbiguousCaseError)
+        );
+    }
+
+    #[test]
+    fn set_config_keeps_digit_values() {
+        let mut b64 = Base64::new_encode_unsigned(&62, STANDARD);
+        assert_eq!(b64.to_string(), "+");
+        b64.set_config(URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "-");
+        assert_eq!(b64.decode_to_unsigned(), Ok(62));
+    }
+
+    #[test]
+    fn reinterpret_config_keeps_characters() {
+        // The characters are kept and read with the new character set
+        let mut b64 = Base64::new_encode_unsigned(&0, STANDARD);
+        b64.reinterpret_config(CRYPT).unwrap();
+        assert_eq!(b64.to_string(), "A");
+        assert_eq!(b64.decode_to_unsigned(), Ok(12));
+        // set_config keeps the digits instead
+        let mut converted = Base64::new_encode_unsigned(&0, STANDARD);
+        converted.set_config(CRYPT);
+        assert_eq!(converted.to_string(), ".");
+        assert_eq!(converted.decode_to_unsigned(), Ok(0));
+        assert_ne!(b64, converted);
+        // Characters missing from the new config are an error and nothing changes
+        let mut b64 = Base64::new_encode_unsigned(&62, STANDARD);
+        assert_eq!(
+            b64.reinterpret_config(URL_SAFE_NO_PADDING),
+            Err(Base64Error::InvalidBase64CharacterError {
+                character: '+',
+                index: 0
+            })
+        );
+        assert_eq!(b64.to_string(), "+");
+        assert_eq!(b64.config(), STANDARD);
+        b64.set_config(URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "-");
+        // Padding is replaced with the padding of the new config
+        let mut b64 = Base64::new_encode_bytes(b"Hi", STANDARD);
+        b64.reinterpret_config(URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(b64.to_string(), "SGk");
+        b64.reinterpret_config(Y64).unwrap();
+        assert_eq!(b64.to_string(), "SGk-");
+        assert_eq!(b64.decode_to_bytes(), b"Hi");
+        // A case insensitive config writes the characters in the case of its character set
+        let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!#$%&()*+,-./:;<>?@[]^_{|}~'";
+        let owned = Config::from_alphabet_str(alphabet, None, None).unwrap();
+        let mut conf = owned.as_config();
+        conf.set_case_insensitive(true).unwrap();
+        let mut b64 = Base64::new_from_string("hello", URL_SAFE_NO_PADDING).unwrap();
+        b64.reinterpret_config(&conf).unwrap();
+        assert_eq!(b64.as_str(), "HELLO");
+    }
+
+    #[test]
+    fn to_string_unwrapped_mime() {
+        let b64 = Base64::new_encode_bytes(&[0; 60], MIME);
+        assert!(b64.to_string().contains("\r\n"));
+        let unwrapped = b64.to_string_unwrapped();
+        assert_eq!(unwrapped, "A".repeat(80));
+    }
+
+    #[test]
+    fn to_string_wrapped_width() {
+        let s = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        let b64 = Base64::new_encode_bytes(s.as_bytes(), MIME);
+        let wrapped = b64.to_string_wrapped(10);
+        let lines: Vec<&str> = wrapped.split("\r\n").collect();
+        assert_eq!(lines.concat(), b64.to_string_unwrapped());
+        for line in &lines[..lines.len() - 1] {
+            assert_eq!(line.len(), 10);
+        }
+        assert!(lines[lines.len() - 1].len() <= 10);
+        // The value isn't changed
+        assert_eq!(b64.as_str().split("\r\n").next().unwrap().len(), 76);
+        assert_eq!(b64.to_string_wrapped(0), b64.to_string_unwrapped());
+        assert_eq!(
+            Base64::new_encode_bytes(b"Hello", STANDARD).to_string_wrapped(4),
+            "SGVs\nbG8="
+        );
+    }
+
+    #[test]
+    fn eq_bytes_ignores_padding_and_line_endings() {
+        let b64 = Base64::new_encode_bytes(b"Hi", MIME);
+        let unpadded = b64.without_padding();
+        assert_ne!(b64, unpadded);
+        assert!(b64.eq_bytes(&unpadded));
+        let long = Base64::new_encode_bytes(&[7; 100], MIME);
+        let single_line = Base64::new_encode_bytes(&[7; 100], URL_SAFE_NO_PADDING);
+        assert!(long.eq_bytes(&single_line));
+        assert!(!b64.eq_bytes(&long));
+    }
+
+    #[test]
+    fn xml_nmtoken_config_valid() {
+        let conf = Config::new(XML_NMTOKEN.get_character_set(), None, None).unwrap();
+        assert_eq!(&conf, XML_NMTOKEN);
+    }
+
+    #[test]
+    fn xml_nmtoken_round_trip() {
+        let b64 = Base64::new_encode_bytes(&[0xFB, 0xFF], XML_NMTOKEN);
+        assert_eq!(b64.to_string(), ".-8");
+        assert_eq!(b64.decode_to_bytes(), vec![0xFB, 0xFF]);
+        let s = "Hello, World!";
+        let b64 = Base64::new_from_string(
+            Base64::new_encode_bytes(s.as_bytes(), XML_NMTOKEN).as_str(),
+            XML_NMTOKEN,
+        )
+        .unwrap();
+        assert_eq!(b64.decode_to_bytes(), s.as_bytes());
+    }
+
+    #[test]
+    fn xml_nmtoken_rejects_standard_characters() {
+        assert!(Base64::new_from_string("+/8", XML_NMTOKEN).is_err());
+        assert!(lb64::decode_config("+/8", XML_NMTOKEN).is_err());
+        assert!(lb64::decode_config("SGk=", XML_NMTOKEN).is_err());
+    }
+
+    #[test]
+    fn decode_to_string_utf8() {
+        for s in &["", "Hello, World!", "😀 emoji ünïcödé"] {
+            let b64 = Base64::new_encode_bytes(s.as_bytes(), MIME);
+            assert_eq!(b64.decode_to_string().unwrap(), *s);
+        }
+    }
+
+    #[test]
+    fn decode_to_string_invalid_utf8() {
+        let b64 = Base64::new_encode_bytes(&[0x48, 0xFF, 0xFE], MIME);
+        assert_eq!(b64.decode_to_string(), Err(Base64Error::InvalidUtf8));
+        assert_eq!(
+            Base64Error::InvalidUtf8.to_string(),
+            "Decoded bytes aren't valid UTF-8"
+        );
+    }
+
+    #[test]
+    fn significant_len_skips_leading_zeros() {
+        let b64 = Base64::new_from_string("AAK", URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(b64.significant_len(), 1);
+        let b64 = Base64::new_from_string("A", URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(b64.significant_len(), 1);
+        let b64 = Base64::new_from_string("BA", URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(b64.significant_len(), 2);
+    }
+
+    #[test]
+    fn significant_len_all_zeros() {
+        let b64 = Base64::new_from_string("AAAA", URL_SAFE_NO_PADDING).unwrap();
+        assert_eq!(b64.significant_len(), 1);
+    }
+
+    #[test]
+    fn trim_leading_zeros_number() {
+        let mut b64 = Base64::new_from_string("AAK", URL_SAFE_NO_PADDING).unwrap();
+        b64.trim_leading_zeros();
+        assert_eq!(b64.as_str(), "K");
+        let mut b64 = Base64::new_encode_unsigned_min_width(&128, 6, URL_SAFE_NO_PADDING);
+        b64.trim_leading_zeros();
+        assert_eq!(b64.as_str(), "CA");
+    }
+
+    #[test]
+    fn trim_leading_zeros_all_zeros() {
+        let mut b64 = Base64::new_from_string("AAA", URL_SAFE_NO_PADDING).unwrap();
+        b64.trim_leading_zeros();
+        assert_eq!(b64.as_str(), "A");
+    }
+
+    #[test]
+    fn new_from_url_string_percent_encoded_padding() {
+        let b64 = Base64::new_from_url_string("SGk%3D", URL_SAFE_PADDING).unwrap();
+        assert_eq!(
+            b64,
+            Base64::new_from_string("SGk=", URL_SAFE_PADDING).unwrap()
+        );
+        assert_eq!(b64.decode_to_bytes(), b"Hi");
+        let b64 = Base64::new_from_url_string("SA%3d%3d", URL_SAFE_PADDING).unwrap();
+        assert_eq!(b64.as_str(), "SA==");
+        assert_eq!(b64.decode_to_bytes(), b"H");
+    }
+
+    #[test]
+    fn new_from_url_string_invalid_percent_encoding() {
+        match Base64::new_from_url_string("SGk%2B", URL_SAFE_PADDING) {
+            Err(Base64Error::InvalidBase64CharacterError { character, index }) => {
+                assert_eq!(character, '%');
+                assert_eq!(index, 3);
+            }
+            _ => panic!("Expected an InvalidBase64CharacterError"),
+        }
+        // Without padding %3D is just as invalid
+        assert!(Base64::new_from_url_string("SGk%3D", URL_SAFE_NO_PADDING).is_err());
+    }
+
+    #[test]
+    fn encode_bytes_with_order_round_trip() {
+        let bytes: &[u8] = b"Hello, World!";
+        let big = Base64::new_encode_bytes_with_order(bytes, MIME, ByteOrder::BigEndian);
+        let little = Base64::new_encode_bytes_with_order(bytes, MIME, ByteOrder::LittleEndian);
+        assert_eq!(big, Base64::new_encode_bytes(bytes, MIME));
+        assert_ne!(big, little);
+        assert_eq!(big.decode_to_bytes_with_order(ByteOrder::BigEndian), bytes);
+        assert_eq!(
+            little.decode_to_bytes_with_order(ByteOrder::LittleEndian),
+            bytes
+        );
+        let mut reversed = bytes.to_vec();
+        reversed.reverse();
+        assert_eq!(little.decode_to_bytes(), reversed);
+        assert_eq!(ByteOrder::default(), ByteOrder::BigEndian);
+    }
+
+    #[test]
+    fn config_is_glyph_relabel_of() {
+        assert!(STANDARD.is_glyph_relabel_of(URL_SAFE_NO_PADDING));
+        assert!(URL_SAFE_PADDING.is_glyph_relabel_of(STANDARD));
+        assert!(STANDARD.is_glyph_relabel_of(MIME));
+        assert!(!STANDARD.is_glyph_relabel_of(BCRYPT));
+        assert!(!BCRYPT.is_glyph_relabel_of(CRYPT));
+    }
+
+    #[test]
+    fn try_new_encode_bytes_within_limit() {
+        // 6 bytes encode to 8 characters
+        let b64 = Base64::try_new_encode_bytes(b"Hello!", MIME, 8).unwrap();
+        assert_eq!(b64, Base64::new_encode_bytes(b"Hello!", MIME));
+        let b64 = Base64::try_new_encode_bytes(b"Hello!", MIME, 9).unwrap();
+        assert_eq!(b64.as_str(), "SGVsbG8h");
+    }
+
+    #[test]
+    fn try_new_encode_bytes_over_limit() {
+        assert_eq!(
+            Base64::try_new_encode_bytes(b"Hello!", MIME, 7),
+            Err(Base64Error::OutputTooLarge)
+        );
+        // Line endings count towards the length
+        let mut conf: Config = MIME.clone();
+        conf.set_line_length(Some(4));
+        assert_eq!(
+            Base64::try_new_encode_bytes(b"Hello!", &conf, 9),
+            Err(Base64Error::OutputTooLarge)
+        );
+        assert!(Base64::try_new_encode_bytes(b"Hello!", &conf, 10).is_ok());
+    }
+
+    #[test]
+    fn display_alternate_shows_config() {
+        let b64 = Base64::new_encode_bytes(b"Hi", MIME);
+        assert_eq!(format!("{}", b64), "SGk=");
+        let alternate = format!("{:#}", b64);
+        assert!(alternate.starts_with("SGk= ("));
+        assert!(alternate.contains("pad='='"));
+        assert!(alternate.contains("wrap=76"));
+        let b64 = Base64::new_encode_bytes(b"Hi", URL_SAFE_NO_PADDING);
+        assert_eq!(
+            format!("{:#}", b64),
+            "SGk (alphabet=A.._, pad=none, wrap=none)"
+        );
+    }
+
+    #[test]
+    fn restore_padding_under_padded() {
+        let mut b64 = Base64::new_encode_bytes(b"H", MIME);
+        b64.strip_padding();
+        assert_eq!(b64.as_str(), "SA");
+        assert!(b64.needs_padding());
+        b64.restore_padding();
+        assert_eq!(b64.as_str(), "SA==");
+        assert!(!b64.needs_padding());
+    }
+
+    #[test]
+    fn restore_padding_over_padded() {
+        // Extra padding is trimmed to the canonical amount
+        let mut b64 = Base64::new_from_string("SGk===", MIME).unwrap();
+        b64.restore_padding();
+        assert_eq!(b64.as_str(), "SGk=");
+        assert!(!b64.needs_padding());
+        assert_eq!(b64.decode_to_bytes(), b"Hi");
+    }
+
+    #[test]
+    fn needs_padding_numbers_and_unpadded_configs() {
+        let b64 = Base64::new_encode_unsigned(&128, MIME);
+        assert!(!b64.needs_padding());
+        let mut b64 = Base64::new_encode_bytes(b"H", URL_SAFE_NO_PADDING);
+        assert!(!b64.needs_padding());
+        b64.restore_padding();
+        assert_eq!(b64.as_str(), "SA");
+    }
+
+    #[test]
+    fn config_digit_to_char() {
+        assert_eq!(STANDARD.digit_to_char(0), Some('A'));
+        assert_eq!(STANDARD.digit_to_char(63), Some('/'));
+        assert_eq!(URL_SAFE_NO_PADDING.digit_to_char(63), Some('_'));
+        assert_eq!(STANDARD.digit_to_char(64), None);
+        assert_eq!(STANDARD.digit_to_char(u128::MAX), None);
+    }
+
+    #[test]
+    fn config_char_to_digit() {
+        assert_eq!(STANDARD.char_to_digit('A'), Some(0));
+        assert_eq!(STANDARD.char_to_digit('/'), Some(63));
+        assert_eq!(STANDARD.char_to_digit('='), None);
+        assert_eq!(STANDARD.char_to_digit('_'), None);
+        for digit in 0..64 {
+            let c = BCRYPT.digit_to_char(digit).unwrap();
+            assert_eq!(BCRYPT.char_to_digit(c), Some(digit));
+        }
+    }
+
+    #[test]
+    fn out_of_range_digit_doesnt_panic() {
+        // digit_to_char uses the crate private decimal_to_base64_char directly
+        assert_eq!(MIME.digit_to_char(64), None);
+        let mut b64 = Base64::new_encode_unsigned(&1, MIME);
+        assert_eq!(b64.push_digit(64), Err(Base64Error::OverflowError));
+        assert_eq!(b64.as_str(), "B");
+    }
+
+    #[test]
+    fn with_config_chains() {
+        let b64 = Base64::new_encode_unsigned(&63, STANDARD).with_config(URL_SAFE_NO_PADDING);
+        assert_eq!(b64, Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING));
+        let b64 =
+            Base64::new_encode_bytes(b"Hi", URL_SAFE_NO_PADDING).with_config(URL_SAFE_PADDING);
+        assert_eq!(b64, Base64::new_encode_bytes(b"Hi", URL_SAFE_PADDING));
+    }
+
+    #[test]
+    fn with_random_len_chains() {
+        let b64 = Base64::default()
+            .with_config(URL_SAFE_NO_PADDING)
+            .with_random_len(12);
+        assert_eq!(b64.len(), 12);
+        assert!(lb64::is_valid(b64.as_str(), URL_SAFE_NO_PADDING));
+    }
+
+    #[test]
+    fn decode_to_slice_exact_fit() {
+        let b64 = Base64::new_encode_bytes(b"Hello", MIME);
+        let mut out = [0u8; 5];
+        assert_eq!(b64.decoded_len(), 5);
+        assert_eq!(b64.decode_to_slice(&mut out), Ok(5));
+        assert_eq!(&out, b"Hello");
+    }
+
+    #[test]
+    fn decode_to_slice_too_small() {
+        let b64 = Base64::new_encode_bytes(b"Hello", MIME);
+        let mut out = [0u8; 4];
+        assert_eq!(
+            b64.decode_to_slice(&mut out),
+            Err(Base64Error::BufferTooSmall)
+        );
+        // Nothing is written
+        assert_eq!(out, [0u8; 4]);
+    }
+
+    #[test]
+    fn decode_to_slice_oversized() {
+        let b64 = Base64::new_encode_bytes(b"Hello", MIME);
+        let mut out = [0xAAu8; 8];
+        assert_eq!(b64.decode_to_slice(&mut out), Ok(5));
+        assert_eq!(&out[..5], b"Hello");
+        assert_eq!(&out[5..], &[0xAA; 3]);
+    }
+
+    #[test]
+    fn encode_to_slice_matches_new_encode_bytes() {
+        let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        for conf in [MIME, STANDARD, URL_SAFE_NO_PADDING, PEM].iter() {
+            let expected: Vec<char> = Base64::new_encode_bytes(s.as_bytes(), conf)
+                .chars()
+                .collect();
+            let mut out = vec!['\0'; lb64::encoded_len(s.len(), conf)];
+            assert_eq!(
+                lb64::encode_to_slice(s.as_bytes(), conf, &mut out),
+                Ok(expected.len())
+            );
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn encode_to_slice_buffer_sizes() {
+        let mut out = ['\0'; 3];
+        assert_eq!(
+            lb64::encode_to_slice(b"Hi", STANDARD, &mut out),
+            Err(Base64Error::BufferTooSmall)
+        );
+        assert_eq!(out, ['\0'; 3]);
+        let mut out = ['x'; 6];
+        assert_eq!(lb64::encode_to_slice(b"Hi", STANDARD, &mut out), Ok(4));
+        assert_eq!(out, ['S', 'G', 'k', '=', 'x', 'x']);
+    }
+
+    #[test]
+    fn chunks_most_significant_first() {
+        let b64 = Base64::new_from_string("abcdef", URL_SAFE_NO_PADDING).unwrap();
+        let chunks = b64.chunks(2);
+        let strings: Vec<&str> = chunks.iter().map(|chunk| chunk.as_str()).collect();
+        assert_eq!(strings, ["ab", "cd", "ef"]);
+        let mut joined = Base64::empty(URL_SAFE_NO_PADDING);
+        for chunk in &chunks {
+            joined = joined.concat(chunk).unwrap();
+        }
+        assert_eq!(joined, b64);
+    }
+
+    #[test]
+    fn chunks_shorter_last_chunk_and_padding() {
+        let b64 = Base64::new_from_string("abcde", URL_SAFE_PADDING).unwrap();
+        let strings: Vec<String> = b64.chunks(2).iter().map(|c| c.to_string()).collect();
+        assert_eq!(strings, ["ab==", "cd==", "e==="]);
+        assert_eq!(b64.chunks(0).len(), 1);
+        assert_eq!(b64.chunks(0)[0], b64);
+    }
+
+    #[test]
+    fn rfc4648_vectors_standard() {
+        assert_eq!(lb64::rfc4648_vectors().len(), 7);
+        for (input, encoded) in lb64::rfc4648_vectors() {
+            let b64 = Base64::new_encode_bytes(input, STANDARD);
+            assert_eq!(b64.as_str(), *encoded);
+            assert_eq!(b64.decode_to_bytes(), *input);
+            let b64 = Base64::new_from_string(encoded, STANDARD).unwrap();
+            assert_eq!(b64.decode_to_bytes_strict(), Ok(input.to_vec()));
+        }
+    }
+
+    #[test]
+    fn rfc4648_vectors_url_safe() {
+        for (input, encoded) in lb64::rfc4648_vectors() {
+            let b64 = Base64::new_encode_bytes(input, URL_SAFE_PADDING);
+            assert_eq!(b64.as_str(), *encoded);
+            let b64 = Base64::new_encode_bytes(input, URL_SAFE_NO_PADDING);
+            assert_eq!(b64.as_str(), encoded.trim_end_matches('='));
+        }
+    }
+
+    #[test]
+    fn one_byte_padding() {
+        assert_eq!(Base64::new_encode_bytes(b"f", STANDARD).as_str(), "Zg==");
+        assert_eq!(Base64::new_encode_bytes(b"f", MIME).as_str(), "Zg==");
+        let b64 = Base64::new_encode_bytes(b"f", STANDARD);
+        assert_eq!(b64.padding_count(), 2);
+        assert_eq!(b64.decode_to_bytes(), b"f");
+    }
+
+    #[test]
+    fn two_byte_padding() {
+        assert_eq!(Base64::new_encode_bytes(b"fo", STANDARD).as_str(), "Zm8=");
+        assert_eq!(Base64::new_encode_bytes(b"fo", MIME).as_str(), "Zm8=");
+        let b64 = Base64::new_encode_bytes(b"fo", MIME);
+        assert_eq!(b64.padding_count(), 1);
+        assert_eq!(b64.decode_to_bytes(), b"fo");
+    }
+
+    #[test]
+    fn new_random_zero_length_is_zero() {
+        let b64 = Base64::new_random(0, URL_SAFE_PADDING);
+        assert_eq!(b64.as_str(), "A");
+        assert_eq!(b64.decode_to_unsigned(), Ok(0));
+        assert_eq!(b64, Base64::new_encode_unsigned(&0, URL_SAFE_PADDING));
+        let mut b64 = Base64::new_random(8, MIME);
+        b64.set_random(0);
+        assert_eq!(b64.as_str(), "A");
+        assert!(!b64.is_empty());
+    }
+
+    #[test]
+    fn as_ref_view_matches_owned() {
+        let b64 = Base64::new_encode_unsigned(&20769187, URL_SAFE_NO_PADDING);
+        let view = b64.as_ref_view();
+        assert_eq!(view.decode_to_unsigned(), b64.decode_to_unsigned());
+        assert_eq!(view.len(), b64.len());
+        assert_eq!(view.to_string(), b64.to_string());
+        let b64 = Base64::new_encode_bytes(b"Hello, World!", MIME);
+        let view = b64.as_ref_view();
+        assert_eq!(view.decode_to_bytes(), b64.decode_to_bytes());
+        assert_eq!(view.decode_to_unsigned(), b64.decode_to_unsigned());
+        assert!(!view.is_empty());
+    }
+
+    #[test]
+    fn require_padding_on_decode_without_padding() {
+        let mut conf: Config = URL_SAFE_NO_PADDING.clone();
+        assert!(!conf.get_require_padding_on_decode());
+        conf.set_require_padding_on_decode(true);
+        assert!(conf.get_require_padding_on_decode());
+        assert_ne!(conf, *URL_SAFE_NO_PADDING);
+        // Encoding still leaves out padding
+        let b64 = Base64::new_encode_bytes(b"Hi", &conf);
+        assert_eq!(b64.as_str(), "SGk");
+        assert_eq!(
+            b64.decode_to_bytes_strict(),
+            Err(Base64Error::NonCanonicalEncoding)
+        );
+        // Non strict decoding isn't affected
+        assert_eq!(b64.decode_to_bytes(), b"Hi");
+        let b64 = Base64::new_encode_bytes(b"Hi!", &conf);
+        assert_eq!(b64.decode_to_bytes_strict(), Ok(b"Hi!".to_vec()));
+        // Without the flag the unpadded value is canonical
+        let b64 = Base64::new_encode_bytes(b"Hi", URL_SAFE_NO_PADDING);
+        assert_eq!(b64.decode_to_bytes_strict(), Ok(b"Hi".to_vec()));
+    }
+
+    #[test]
+    fn require_padding_on_decode_with_padding() {
+        let mut conf: Config = STANDARD.clone();
+        conf.set_require_padding_on_decode(true);
+        let b64 = Base64::new_encode_bytes(b"Hi", &conf);
+        assert_eq!(b64.decode_to_bytes_strict(), Ok(b"Hi".to_vec()));
+        let mut b64 = Base64::new_encode_bytes(b"Hi", &conf);
+        b64.strip_padding();
+        assert_eq!(
+            b64.decode_to_bytes_strict(),
+            Err(Base64Error::NonCanonicalEncoding)
+        );
+    }
+
+    #[test]
+    fn reader_round_trip() {
+        use std::io::Cursor;
+        let bytes: Vec<u8> = (0..5000u32).map(|i| (i * 31 % 251) as u8).collect();
+        for conf in &[MIME, STANDARD, URL_SAFE_NO_PADDING] {
+            for len in &[0, 1, 2, 3, 3071, 3072, 3073, 5000] {
+                let mut encoded = Vec::new();
+                let read = encode_reader(&mut Cursor::new(&bytes[..*len]), &mut encoded, conf);
+                assert_eq!(read.unwrap(), *len as u64);
+                let encoded = String::from_utf8(encoded).unwrap();
+                assert_eq!(
+                    encoded,
+                    Base64::new_encode_bytes(&bytes[..*len], conf).to_string()
+                );
+                let mut decoded = Vec::new();
+                let read = decode_reader(&mut Cursor::new(&encoded), &mut decoded, conf);
+                assert_eq!(read.unwrap(), encoded.len() as u64);
+                assert_eq!(decoded, &bytes[..*len]);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_reader_invalid_character() {
+        use std::io::{Cursor, ErrorKind};
+        let mut decoded = Vec::new();
+        let err = decode_reader(&mut Cursor::new("SGk*"), &mut decoded, STANDARD).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let mut decoded = Vec::new();
+        let err = decode_reader(
+            &mut Cursor::new(vec![b'S', 0xE2, 0x82]),
+            &mut decoded,
+            STANDARD,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn config_accessor() {
+        let b64 = Base64::new_encode_bytes(b"Hi", URL_SAFE_NO_PADDING);
+        assert_eq!(b64.config(), URL_SAFE_NO_PADDING);
+        assert_eq!(b64.config().get_padding(), None);
+        let other = Base64::new_encode_bytes(b"Hi", b64.config());
+        assert_eq!(other, b64);
+    }
+
+    #[test]
+    fn debug_is_compact() {
+        let b64 = Base64::new_encode_bytes(b"Hi", MIME);
+        assert_eq!(
+            format!("{:?}", b64),
+            "Base64 { value: \"SGk=\", len: 4, padding: Some('=') }"
+        );
+        let bytes: Vec<u8> = (0..=255).collect();
+        let b64 = Base64::new_encode_bytes(&bytes, MIME);
+        let debug = format!("{:?}", b64);
+        assert!(debug.len() < 100, "{}", debug);
+        assert!(debug.contains(&format!("len: {}", b64.len())));
+        let debug = format!("{:?}", STANDARD);
+        assert!(debug.contains("\"ABCD..89+/\""), "{}", debug);
+        assert!(debug.len() < 250, "{}", debug);
+    }
+
+    #[test]
+    fn cmp_equal_agrees_with_eq() {
+        use std::cmp::Ordering;
+        let pairs = [
+            (
+                Base64::new_from_string("+", STANDARD).unwrap(),
+                Base64::new_from_string("-", URL_SAFE_NO_PADDING).unwrap(),
+            ),
+            (
+                Base64::new_encode_bytes(b"Hi", STANDARD),
+                Base64::new_encode_bytes(b"Hi", URL_SAFE_NO_PADDING),
+            ),
+            (
+                Base64::new_encode_unsigned(&128, URL_SAFE_PADDING),
+                Base64::new_encode_unsigned(&128, URL_SAFE_NO_PADDING),
+            ),
+        ];
+        for (a, b) in pairs.iter() {
+            assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+            assert_eq!(b.cmp(a) == Ordering::Equal, b == a);
+            assert_eq!(a.cmp(b), b.cmp(a).reverse());
+        }
+        // Same value still orders before a larger one
+        let larger = Base64::new_encode_unsigned(&63, URL_SAFE_NO_PADDING);
+        assert!(pairs[0].0 < larger && pairs[0].1 < larger);
+    }
+
+    #[test]
+    fn decode_to_bytes_ct_padding_and_whitespace() {
+        let s: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        for conf in &[MIME, STANDARD, URL_SAFE_NO_PADDING] {
+            for n in 0..s.len() {
+                let b64 = Base64::new_encode_bytes(&s.as_bytes()[..n], conf);
+                assert_eq!(b64.decode_to_bytes_ct(), Ok(s.as_bytes()[..n].to_vec()));
+            }
+        }
+        let b64 = Base64::new_from_string("SGVs\nbG8=", MIME).unwrap();
+        assert_eq!(b64.decode_to_bytes_ct(), Ok(b"Hello".to_vec()));
+        let b64 = Base64::new_from_string("SGk=", STANDARD).unwrap();
+        assert_eq!(b64.decode_to_bytes_ct(), Ok(b"Hi".to_vec()));
+    }
+
+    #[test]
+    fn encoded_len_overflow() {
+        // input_len * 4 overflows but the encoded length fits
+        let len = usize::MAX / 2;
+        let expected = len.div_ceil(3) * 4;
+        assert_eq!(lb64::checked_encoded_len(len, STANDARD), Some(expected));
+        assert_eq!(lb64::encoded_len(len, STANDARD), expected);
+        assert!(lb64::checked_encoded_len(len, MIME).unwrap() > expected);
+        // Lengths that don't fit
+        assert_eq!(lb64::checked_encoded_len(usize::MAX, STANDARD), None);
+        assert_eq!(lb64::encoded_len(usize::MAX, STANDARD), usize::MAX);
+        assert_eq!(
+            lb64::checked_encoded_len(usize::MAX / 4 * 3 + 3, MIME),
+            None
+        );
+        assert_eq!(lb64::checked_encoded_len(5, MIME), Some(8));
+        // The guard can't be bypassed by a length that wraps around
+        let b64 = Base64::try_new_encode_bytes(b"Hello", MIME, 8);
+        assert_eq!(b64.unwrap().to_string(), "SGVsbG8=");
+        assert_eq!(
+            lb64::checked_encoded_len(usize::MAX, URL_SAFE_NO_PADDING),
+            None
+        );
+        // The largest input whose encoding fits
+        let len = usize::MAX / 4 * 3;
+        assert_eq!(
+            lb64::checked_encoded_len(len, URL_SAFE_NO_PADDING),
+            Some(len / 3 * 4)
+        );
+        let mut out = ['\0'; 8];
+        assert_eq!(lb64::encode_to_slice(b"Hi", STANDARD, &mut out), Ok(4));
+    }
+
+    #[test]
+    fn eq_agrees_with_cmp_across_configs() {
+        use std::cmp::Ordering;
+        let bcrypt = Base64::new_from_string("B", BCRYPT).unwrap();
+        let crypt = Base64::new_from_string("B", CRYPT).unwrap();
+        assert_ne!(bcrypt, crypt);
+        assert!(bcrypt < crypt);
+        let values = [
+            bcrypt,
+            crypt,
+            Base64::new_from_string("B", STANDARD).unwrap(),
+            Base64::new_from_string("B", Y64).unwrap(),
+            Base64::new_from_string("+", STANDARD).unwrap(),
+            Base64::new_from_string("+", MIME).unwrap(),
+            Base64::new_from_string("-", URL_SAFE_NO_PADDING).unwrap(),
+            Base64::new_encode_bytes(b"Hi", STANDARD),
+            Base64::new_encode_bytes(b"Hi", URL_SAFE_PADDING),
+            Base64::new_encode_bytes(b"Hi", BCRYPT),
+        ];
+        for a in values.iter() {
+            for b in values.iter() {
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{} {}", a, b);
+            }
+        }
+        assert_eq!(values[4], values[5]);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    extern crate serde_test;
+
+    use self::serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    use lb64::config::STANDARD;
+    use lb64::Base64;
+
+    #[test]
+    fn serde_round_trip() {
+        let b64 = Base64::new_encode_bytes("Hello".as_bytes(), STANDARD);
+        assert_tokens(&b64, &[Token::Str("SGVsbG8=")]);
+    }
+
+    #[test]
+    fn serde_invalid_character() {
+        assert_de_tokens_error::<Base64>(&[Token::Str("^_^")], "Invalid character '^' at index 0");
+    }
+}
+
+#[cfg(all(test, feature = "compat"))]
+mod compat_tests {
+    use lb64::config::{MIME, URL_SAFE_NO_PADDING};
+    use lb64::error::Base64Error;
+    use lb64::Base64;
+
+    #[test]
+    fn from_standard_base64() {
+        let b64 = Base64::from_standard_base64("SGVsbG8sIFdvcmxkIQ==").unwrap();
+        assert_eq!(b64.decode_to_bytes(), b"Hello, World!");
+        assert_eq!(b64.to_string(), "SGVsbG8sIFdvcmxkIQ==");
+        // Missing padding is added
+        assert_eq!(
+            Base64::from_standard_base64("SGk").unwrap().to_string(),
+            "SGk="
+        );
+        assert_eq!(
+            Base64::from_standard_base64("-_8="),
+            Err(Base64Error::InvalidBase64CharacterError {
+                character: '-',
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn to_standard_base64() {
+        let b64 = Base64::new_encode_bytes(&[251, 255], URL_SAFE_NO_PADDING);
+        assert_eq!(b64.to_string(), "-_8");
+        assert_eq!(b64.to_standard_base64(), "+/8=");
+        let b64 = Base64::new_encode_bytes(b"Man", MIME);
+        assert_eq!(b64.to_standard_base64(), "TWFu");
+        let b64 = Base64::new_encode_bytes(b"", MIME);
+        assert_eq!(b64.to_standard_base64(), "");
+    }
+}
+
+#[cfg(all(test, feature = "simd"))]
+mod simd_tests {
+    use lb64::config::{Config, BCRYPT, IMAP, MIME, STANDARD, URL_SAFE_NO_PADDING};
+    use lb64::Base64;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const CONFIGS: [&Config; 5] = [STANDARD, MIME, URL_SAFE_NO_PADDING, IMAP, BCRYPT];
+
+    fn random_bytes(rng: &mut StdRng, len: usize) -> Vec<u8> {
+        (0..len).map(|_| rng.gen()).collect()
+    }
+
+    #[test]
+    fn simd_encode_matches_scalar() {
+        let mut rng = StdRng::seed_from_u64(64);
+        for conf in CONFIGS.iter() {
+            for len in 0..200 {
+                let bytes = random_bytes(&mut rng, len);
+                // encode_iter always uses the scalar path
+                let scalar: String = lb64::encode_iter(&bytes, conf).collect();
+                let b64 = Base64::new_encode_bytes(&bytes, conf);
+                assert_eq!(b64.as_str(), scalar);
+            }
+        }
+    }
+
+    #[test]
+    fn simd_decode_matches_scalar() {
+        let mut rng = StdRng::seed_from_u64(46);
+        for conf in CONFIGS.iter() {
+            for len in 0..200 {
+                let bytes = random_bytes(&mut rng, len);
+                let b64 = Base64::new_encode_bytes(&bytes, conf);
+                // decode_iter always uses the scalar path
+                let scalar: Result<Vec<u8>, _> = lb64::decode_iter(b64.chars(), conf).collect();
+                assert_eq!(b64.try_decode_to_bytes(), scalar);
+                assert_eq!(b64.decode_to_bytes(), bytes);
+            }
+        }
+    }
+
+    #[test]
+    fn simd_decode_invalid_character_matches_scalar() {
+        let mut rng = StdRng::seed_from_u64(128);
+        let bytes = random_bytes(&mut rng, 120);
+        let mut encoded = Base64::new_encode_bytes(&bytes, STANDARD).to_string();
+        encoded.replace_range(37..38, "*");
+        let simd = lb64::decode_config(&encoded, STANDARD);
+        let scalar: Result<Vec<u8>, _> = lb64::decode_iter(encoded.chars(), STANDARD).collect();
+        assert_eq!(simd, scalar);
+        match simd {
+            Err(lb64::error::Base64Error::InvalidBase64CharacterError { character, index }) => {
+                assert_eq!(character, '*');
+                assert_eq!(index, 37);
+            }
+            _ => panic!("Expected an InvalidBase64CharacterError"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod rayon_tests {
+    use lb64::config::{Config, MIME, STANDARD, URL_SAFE_NO_PADDING};
+    use lb64::Base64;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn parallel_encode_matches_sequential() {
+        let mut rng = StdRng::seed_from_u64(3);
+        // Several chunks with a partial last chunk and group
+        let bytes: Vec<u8> = (0..(1 << 20) + 5).map(|_| rng.gen()).collect();
+        let configs: [&Config; 3] = [STANDARD, MIME, URL_SAFE_NO_PADDING];
+        for conf in configs.iter() {
+            let parallel = Base64::new_encode_bytes_parallel(&bytes, conf);
+            let sequential = Base64::new_encode_bytes(&bytes, conf);
+            assert_eq!(parallel.as_str(), sequential.as_str());
+            assert_eq!(parallel.decode_to_bytes(), bytes);
+        }
+    }
+
+    #[test]
+    fn parallel_encode_line_wrapping_across_chunks() {
+        // A line length that doesn't divide the chunk length so lines cross chunk boundaries
+        let mut conf: Config = MIME.clone();
+        conf.set_line_length(Some(7));
+        let bytes: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+        let parallel = Base64::new_encode_bytes_parallel(&bytes, &conf);
+        assert_eq!(parallel, Base64::new_encode_bytes(&bytes, &conf));
+    }
+
+    #[test]
+    fn parallel_encode_empty() {
+        let b64 = Base64::new_encode_bytes_parallel(&[], STANDARD);
+        assert_eq!(b64.as_str(), "");
+    }
 }